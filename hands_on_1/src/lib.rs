@@ -1,4 +1,11 @@
-use std::{cmp::Ord, iter::Sum, ops::Add};
+use std::{
+    cmp::Ord,
+    collections::{HashSet, VecDeque},
+    fmt::{self, Display, Formatter},
+    iter::{Sum, successors},
+    ops::Add,
+    str::FromStr,
+};
 
 pub struct Node<T> {
     pub key: T,
@@ -205,93 +212,272 @@ impl<T> Tree<T> {
             None
         }
     }
+
+    /// Returns the node ids in postorder (left subtree, right subtree, node).
+    ///
+    /// The traversal uses an explicit stack instead of recursion, so it works
+    /// on degenerate trees of any depth.
+    fn postorder_ids(&self) -> Vec<usize> {
+        let mut order = Vec::with_capacity(self.nodes.len());
+        let mut stack = if self.nodes.is_empty() {
+            vec![]
+        } else {
+            vec![0]
+        };
+        // Visit node, right, left (a reversed postorder) then reverse it
+        while let Some(id) = stack.pop() {
+            order.push(id);
+            let node = &self.nodes[id];
+            stack.extend(node.id_left);
+            stack.extend(node.id_right);
+        }
+        order.reverse();
+        order
+    }
 }
 
-impl<T> Tree<T>
-where
-    T: Add<Output = T> + Copy,
-{
-    /// Computes the sum of all node keys in the tree.
+impl<T> Tree<T> {
+    /// Builds a tree from its level order representation (as used by LeetCode), where
+    /// `None` marks a missing child.
+    ///
+    /// Every value (but `None`) reserves two slots in the following part of the array,
+    /// for its left and right child. Values that exceed the slots are ignored.
     ///
     /// # Returns
-    /// Returns `None` if the tree is empty
     ///
-    pub fn sum(&self) -> Option<T> {
-        if self.get_node(0).is_none() {
-            None
-        } else {
-            Some(self.rec_sum(0))
+    /// An empty tree if `a` is empty or starts with `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hands_on_1::Tree;
+    ///
+    /// let tree = Tree::from_level_order(&[Some(1), None, Some(2), Some(3)]);
+    /// assert_eq!(tree.nodes[0].id_left, None);
+    /// assert_eq!(tree.nodes[2].key, 3);
+    /// ```
+    pub fn from_level_order(a: &[Option<T>]) -> Self
+    where
+        T: Clone,
+    {
+        let Some(Some(root)) = a.first() else {
+            return Self::new();
+        };
+        let mut tree = Self::with_root(root.clone());
+        let mut parents = VecDeque::from([0]);
+        let mut slots = a[1..].chunks(2);
+
+        while let Some(parent) = parents.pop_front()
+            && let Some(children) = slots.next()
+        {
+            for (child, is_left) in children.iter().zip([true, false]) {
+                if let Some(key) = child {
+                    let id = tree.add_node(parent, key.clone(), is_left).unwrap();
+                    parents.push_back(id);
+                }
+            }
+        }
+        tree
+    }
+
+    /// Returns the level order representation of the tree, the inverse of
+    /// [`from_level_order`]: nodes by increasing depth, with `None` for the missing
+    /// children of every node and no trailing `None`.
+    pub fn to_level_order(&self) -> Vec<Option<T>>
+    where
+        T: Clone,
+    {
+        let mut res = vec![];
+        let mut queue: VecDeque<Option<usize>> = VecDeque::new();
+        if !self.nodes.is_empty() {
+            queue.push_back(Some(0));
+        }
+        while let Some(slot) = queue.pop_front() {
+            match slot {
+                Some(id) => {
+                    let node = &self.nodes[id];
+                    res.push(Some(node.key.clone()));
+                    queue.push_back(node.id_left);
+                    queue.push_back(node.id_right);
+                }
+                None => res.push(None),
+            }
+        }
+        while let Some(None) = res.last() {
+            res.pop();
         }
+        res
     }
 
-    /// Recursively computes the sum of the subtree rooted at `node_id`.
+    /// Parses a tree written in bracket notation: a node is its key followed by the
+    /// subtrees of its children, each one between parentheses. A missing left child is
+    /// written as `()`, a missing right child is omitted.
     ///
-    /// # Returns
+    /// Whitespace between tokens is ignored, an empty string is an empty tree.
+    ///
+    /// # Errors
     ///
-    /// The sum of all noe keys in the tree as a type
+    /// Returns an error if the parentheses are unbalanced, a node has more than two
+    /// children or a key can't be parsed as `T`.
     ///
-    /// # Panics
+    /// # Examples
+    ///
+    /// ```
+    /// use hands_on_1::Tree;
     ///
-    /// Panics if `node_id` is invalid (should only be called internally with valid IDs)
-    fn rec_sum(&self, node_id: usize) -> T {
-        let node = self.get_node(node_id).unwrap();
-        match (node.id_left, node.id_right) {
-            (None, None) => node.key,
-            (Some(s), None) | (None, Some(s)) => node.key + self.rec_sum(s),
-            (Some(s0), Some(s1)) => node.key + self.rec_sum(s0) + self.rec_sum(s1),
+    /// let tree: Tree<i32> = Tree::from_brackets("1(2(4)(5))(3()(6))").unwrap();
+    /// assert_eq!(tree.nodes.len(), 6);
+    /// assert_eq!(tree.to_brackets(), "1(2(4)(5))(3()(6))");
+    /// ```
+    pub fn from_brackets(s: &str) -> Result<Self, &'static str>
+    where
+        T: FromStr,
+    {
+        // Reads a key from the start of `s`, returns it with the rest of the string
+        fn key<T: FromStr>(s: &str) -> Result<(T, &str), &'static str> {
+            let end = s.find(['(', ')']).unwrap_or(s.len());
+            match s[..end].trim() {
+                "" => Err("Missing key"),
+                k => k.parse().map(|k| (k, &s[end..])).map_err(|_| "Invalid key"),
+            }
+        }
+
+        let s = s.trim();
+        if s.is_empty() {
+            return Ok(Self::new());
+        }
+        let (root, mut rest) = key(s)?;
+        let mut tree = Self::with_root(root);
+        // Nodes whose subtree is still open, with the number of children read so far
+        let mut open = vec![(0, 0)];
+
+        loop {
+            rest = rest.trim_start();
+            let Some(c) = rest.chars().next() else {
+                break;
+            };
+            rest = &rest[c.len_utf8()..];
+            match c {
+                '(' => {
+                    let Some((parent, children)) = open.last_mut() else {
+                        return Err("Unbalanced parentheses");
+                    };
+                    if *children == 2 {
+                        return Err("A node has at most two children");
+                    }
+                    *children += 1;
+                    let (parent, is_left) = (*parent, *children == 1);
+
+                    rest = rest.trim_start();
+                    if let Some(after) = rest.strip_prefix(')') {
+                        // Empty subtree
+                        rest = after;
+                        continue;
+                    }
+                    let (k, after) = key(rest)?;
+                    rest = after;
+                    let id = tree.add_node(parent, k, is_left).unwrap();
+                    open.push((id, 0));
+                }
+                ')' => {
+                    // Closes the subtree of the last open node (the root is never closed)
+                    if open.len() < 2 {
+                        return Err("Unbalanced parentheses");
+                    }
+                    open.pop();
+                }
+                _ => return Err("Unexpected key"),
+            }
         }
+
+        if open.len() != 1 {
+            return Err("Unbalanced parentheses");
+        }
+        Ok(tree)
+    }
+
+    /// Returns the tree in bracket notation, the inverse of [`from_brackets`].
+    pub fn to_brackets(&self) -> String
+    where
+        T: Display,
+    {
+        // Either a node to print or a piece of text
+        enum Item<'a> {
+            Node(usize),
+            Text(&'a str),
+        }
+
+        let mut res = String::new();
+        let mut stack = if self.nodes.is_empty() {
+            vec![]
+        } else {
+            vec![Item::Node(0)]
+        };
+        while let Some(item) = stack.pop() {
+            match item {
+                Item::Text(s) => res.push_str(s),
+                Item::Node(id) => {
+                    let node = &self.nodes[id];
+                    res.push_str(&node.key.to_string());
+                    // Pushed in reverse order
+                    if let Some(r) = node.id_right {
+                        stack.extend([Item::Text(")"), Item::Node(r), Item::Text("(")]);
+                    }
+                    match node.id_left {
+                        Some(l) => stack.extend([Item::Text(")"), Item::Node(l), Item::Text("(")]),
+                        None if node.id_right.is_some() => stack.push(Item::Text("()")),
+                        None => {}
+                    }
+                }
+            }
+        }
+        res
     }
 }
 
 impl<T> Tree<T>
 where
-    T: Ord,
+    T: Add<Output = T> + Copy,
 {
-    /// Checks if the entire tree satisfies the Binary Search Tree (BST) property.
+    /// Computes the sum of all node keys in the tree.
     ///
-    /// Returns `true` if the tree is empty or if all nodes satisfy BST ordering.
+    /// # Returns
+    /// Returns `None` if the tree is empty
     ///
-    pub fn is_bst(&self) -> bool {
-        if self.nodes.is_empty() {
-            return true; // empty tree is a BST
-        }
-        self.is_bst_rec(0, None, None)
+    pub fn sum(&self) -> Option<T> {
+        self.postorder_ids()
+            .into_iter()
+            .map(|id| self.nodes[id].key)
+            .reduce(|acc, key| acc + key)
     }
+}
 
-    /// Recursively checks if the subtree rooted at `node_id` is a BST,
-    /// enforcing all keys > `min` and < `max` if those bounds are specified.
+impl<T> Tree<T>
+where
+    T: Ord,
+{
+    /// Checks if the entire tree satisfies the Binary Search Tree (BST) property.
     ///
-    /// # Panics
+    /// Returns `true` if the tree is empty or if all nodes satisfy BST ordering.
     ///
-    /// Panics if `node_id` is invalid (should only be called internally).
-    fn is_bst_rec(&self, node_id: usize, min: Option<&T>, max: Option<&T>) -> bool {
-        let node = self.get_node(node_id).unwrap();
-        let key = &node.key;
-
-        // Check current node key against min and max bounds
-        if let Some(min_key) = min
-            && key < min_key
-        {
-            false
-        } else if let Some(max_key) = max
-            && key >= max_key
-        {
-            false
-        }
-        // Recursively check left subtree with updated max bound
-        else if let Some(left_id) = node.id_left
-            && !self.is_bst_rec(left_id, min, Some(key))
-        {
-            false
-        }
-        // Recursively check right subtree with updated min bound
-        else if let Some(right_id) = node.id_right
-            && !self.is_bst_rec(right_id, Some(key), max)
-        {
-            false
-        } else {
-            true
+    pub fn is_bst(&self) -> bool {
+        // (min, max) key of every subtree visited so far, children before parents
+        let mut bounds: Vec<(&T, &T)> = vec![];
+        for id in self.postorder_ids() {
+            let node = &self.nodes[id];
+            let key = &node.key;
+            let right = node.id_right.map(|_| bounds.pop().unwrap());
+            let left = node.id_left.map(|_| bounds.pop().unwrap());
+
+            // Left keys must be < key, right keys must be >= key
+            if left.is_some_and(|(_, l_max)| l_max >= key)
+                || right.is_some_and(|(r_min, _)| r_min < key)
+            {
+                return false;
+            }
+            bounds.push((left.map_or(key, |l| l.0), right.map_or(key, |r| r.1)));
         }
+        true
     }
 }
 
@@ -303,60 +489,238 @@ where
     ///
     /// Returns `None` if the tree is empty or contains no path between two leaves.
     pub fn max_path_sum(&self) -> Option<T> {
-        if self.get_node(0).is_some() {
-            self.max_path_sum_rec(0).1
-        } else {
-            None
+        // For every subtree visited so far, children before parents:
+        // (max path from a leaf to its root, max path sum between two of its leaves)
+        let mut results: Vec<(T, Option<T>)> = vec![];
+        for id in self.postorder_ids() {
+            let node = &self.nodes[id];
+            let key = node.key;
+            let right = node.id_right.map(|_| results.pop().unwrap());
+            let left = node.id_left.map(|_| results.pop().unwrap());
+
+            let res = match (left, right) {
+                //leaf
+                (None, None) => (key, None),
+                //only one child
+                (Some((path, sum)), None) | (None, Some((path, sum))) => (path + key, sum),
+                //2 children
+                (Some((path_l, sum_l)), Some((path_r, sum_r))) => {
+                    let sum = path_l + path_r + key;
+                    let max_sum = match (sum_l, sum_r) {
+                        (None, None) => sum,
+                        (Some(s), None) | (None, Some(s)) => sum.max(s),
+                        (Some(s1), Some(s2)) => sum.max(s1).max(s2),
+                    };
+                    (key + path_r.max(path_l), Some(max_sum))
+                }
+            };
+            results.push(res);
         }
+        results.pop().and_then(|(_, sum)| sum)
     }
 
-    /// Recursively computes two values for the subtree rooted at `id`:
+    /// Computes the maximum path sum between any two leaves in the tree, together with
+    /// the path that achieves it.
     ///
-    /// 1. The maximum path sum from a leaf to the root of this subtree.
-    /// 2. The maximum path sum between any two leaves in this subtree.
+    /// # Returns
     ///
-    /// Returns a tuple `(max_path_to_root, max_path_sum_between_leaves)`.
+    /// The sum and the ids of the nodes on the path, from the leaf in the left subtree of
+    /// its highest node to the leaf in the right one. `None` as for `max_path_sum`.
     ///
-    /// # Explanation
+    /// Ties are broken deterministically: among paths with the same sum the one whose
+    /// highest node comes first in postorder wins, and each branch goes down to the left
+    /// child when both children lead to the same sum.
     ///
-    /// - If the node is a leaf, the max path to root is its key and there is no leaf-to-leaf path (None).
-    /// - If the node has only one child, propagate the child's max path to root plus this node's key.
-    /// - If the node has two children, compute max paths from both sides, and possibly update the max leaf-to-leaf sum.
+    /// # Examples
+    /// ```
+    /// use hands_on_1::Tree;
     ///
-    /// # Panics
+    /// let tree: Tree<i32> = Tree::from_brackets("10(2(20)(1))(10()(-25(3)(4)))").unwrap();
+    /// assert_eq!(tree.max_path_sum_with_path(), Some((23, vec![2, 1, 3])));
+    /// ```
+    pub fn max_path_sum_with_path(&self) -> Option<(T, Vec<usize>)> {
+        // The child on the max path from a leaf to every node
+        let mut down = vec![None; self.nodes.len()];
+        // (sum, highest node) of the best path between two leaves
+        let mut best: Option<(T, usize)> = None;
+        // Max path from a leaf to the root of every subtree visited so far
+        let mut paths: Vec<T> = vec![];
+        for id in self.postorder_ids() {
+            let node = &self.nodes[id];
+            let key = node.key;
+            let right = node.id_right.map(|_| paths.pop().unwrap());
+            let left = node.id_left.map(|_| paths.pop().unwrap());
+
+            let path = match (left, right) {
+                (None, None) => key,
+                (Some(path), None) => {
+                    down[id] = node.id_left;
+                    path + key
+                }
+                (None, Some(path)) => {
+                    down[id] = node.id_right;
+                    path + key
+                }
+                (Some(path_l), Some(path_r)) => {
+                    let sum = path_l + path_r + key;
+                    if best.is_none_or(|(s, _)| sum > s) {
+                        best = Some((sum, id));
+                    }
+                    if path_r > path_l {
+                        down[id] = node.id_right;
+                        key + path_r
+                    } else {
+                        down[id] = node.id_left;
+                        key + path_l
+                    }
+                }
+            };
+            paths.push(path);
+        }
+
+        let (sum, top) = best?;
+        let branch = |start: Option<usize>| successors(start, |&id| down[id]);
+        let mut path: Vec<usize> = branch(self.nodes[top].id_left).collect();
+        path.reverse();
+        path.push(top);
+        path.extend(branch(self.nodes[top].id_right));
+        Some((sum, path))
+    }
+}
+
+impl<T: Display> Display for Tree<T> {
+    /// Draws the tree sideways with box-drawing characters: the root on the first column,
+    /// right subtrees above their parent and left subtrees below it.
     ///
-    /// Panics if `id` is invalid (should only be called internally).
-    fn max_path_sum_rec(&self, id: usize) -> (T, Option<T>) {
-        let node = self.get_node(id).unwrap();
-        let key = node.key;
+    /// ```text
+    /// ┌── 3
+    /// │   └── 6
+    /// 1
+    /// │   ┌── 5
+    /// └── 2
+    ///     └── 4
+    /// ```
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        // A node still to draw with the prefix of its lines and its side (`None` for the
+        // root), or one of the lines already built
+        enum Item {
+            Node(usize, String, Option<bool>),
+            Line(String),
+        }
 
-        match (node.id_left, node.id_right) {
-            (None, None) => {
-                //leaf
-                (key, None)
-            }
-            (Some(c), None) | (None, Some(c)) => {
-                //only one child
-                let (path, sum) = self.max_path_sum_rec(c);
-                (path + key, sum)
+        let mut stack = if self.nodes.is_empty() {
+            vec![]
+        } else {
+            vec![Item::Node(0, String::new(), None)]
+        };
+        while let Some(item) = stack.pop() {
+            match item {
+                Item::Line(line) => writeln!(f, "{line}")?,
+                Item::Node(id, prefix, side) => {
+                    let node = &self.nodes[id];
+                    let (line, above, below) = match side {
+                        None => (String::new(), "", ""),
+                        Some(true) => (format!("{prefix}└── "), "│   ", "    "),
+                        Some(false) => (format!("{prefix}┌── "), "    ", "│   "),
+                    };
+                    // Pushed in reverse order: right subtree, node, left subtree
+                    if let Some(l) = node.id_left {
+                        stack.push(Item::Node(l, format!("{prefix}{below}"), Some(true)));
+                    }
+                    stack.push(Item::Line(format!("{line}{}", node.key)));
+                    if let Some(r) = node.id_right {
+                        stack.push(Item::Node(r, format!("{prefix}{above}"), Some(false)));
+                    }
+                }
             }
-            (Some(c1), Some(c2)) => {
-                //2 children
-                let (path_l, sum_l) = self.max_path_sum_rec(c1);
-                let (path_r, sum_r) = self.max_path_sum_rec(c2);
-
-                let sum = path_l + path_r + key;
-                let path = key + path_r.max(path_l);
+        }
+        Ok(())
+    }
+}
 
-                let max_sum = match (sum_l, sum_r) {
-                    (None, None) => sum,
-                    (Some(s), None) | (None, Some(s)) => sum.max(s),
-                    (Some(s1), Some(s2)) => sum.max(s1).max(s2),
-                };
+impl<T: Display> Tree<T> {
+    /// Returns the tree in the Graphviz DOT language, e.g. to be rendered with
+    /// `dot -Tsvg`. Nodes are named by their position in preorder, whatever their id.
+    ///
+    /// The nodes in `path` (given by id) and the edges between consecutive nodes of
+    /// `path` are highlighted, e.g. the path found by `max_path_sum_with_path`. An
+    /// invisible child keeps a single child on its side.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The ids of the nodes to highlight, possibly empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hands_on_1::Tree;
+    ///
+    /// let tree: Tree<i32> = Tree::from_brackets("1(2)(3)").unwrap();
+    /// let dot = tree.to_dot(&[1, 0]);
+    /// assert!(dot.contains("n0 -> n1 [color=red, penwidth=2];"));
+    /// assert!(dot.contains("n0 -> n2;"));
+    /// ```
+    pub fn to_dot(&self, path: &[usize]) -> String {
+        const HIGHLIGHT: &str = "color=red, penwidth=2";
+        let on_path: HashSet<usize> = path.iter().copied().collect();
+        let edges: HashSet<(usize, usize)> = path
+            .windows(2)
+            .flat_map(|w| [(w[0], w[1]), (w[1], w[0])])
+            .collect();
+
+        // Ids in preorder, and the position of every id in it
+        let mut order = Vec::with_capacity(self.nodes.len());
+        let mut stack = if self.nodes.is_empty() {
+            vec![]
+        } else {
+            vec![0]
+        };
+        while let Some(id) = stack.pop() {
+            order.push(id);
+            stack.extend(self.nodes[id].id_right);
+            stack.extend(self.nodes[id].id_left);
+        }
+        let mut position = vec![0; self.nodes.len()];
+        for (i, &id) in order.iter().enumerate() {
+            position[id] = i;
+        }
 
-                (path, Some(max_sum))
+        let mut res = String::from("digraph tree {\n    node [shape=circle];\n");
+        for (i, &id) in order.iter().enumerate() {
+            let node = &self.nodes[id];
+            let label = node
+                .key
+                .to_string()
+                .replace('\\', "\\\\")
+                .replace('"', "\\\"");
+            let style = if on_path.contains(&id) {
+                format!(", {HIGHLIGHT}")
+            } else {
+                String::new()
+            };
+            res.push_str(&format!("    n{i} [label=\"{label}\"{style}];\n"));
+
+            for (child, side) in [(node.id_left, "l"), (node.id_right, "r")] {
+                match (child, node.id_left.or(node.id_right)) {
+                    (Some(c), _) => {
+                        let style = if edges.contains(&(id, c)) {
+                            format!(" [{HIGHLIGHT}]")
+                        } else {
+                            String::new()
+                        };
+                        res.push_str(&format!("    n{i} -> n{}{style};\n", position[c]));
+                    }
+                    // Placeholder for the missing side of a node with one child
+                    (None, Some(_)) => {
+                        res.push_str(&format!("    n{i}{side} [style=invis];\n"));
+                        res.push_str(&format!("    n{i} -> n{i}{side} [style=invis];\n"));
+                    }
+                    (None, None) => {}
+                }
             }
         }
+        res.push_str("}\n");
+        res
     }
 }
 
@@ -583,6 +947,47 @@ mod tests {
         assert_eq!(mixed_tree.max_path_sum(), Some(65));
     }
 
+    /// Tests the path returned with the maximum path sum, and how ties are broken.
+    #[test]
+    fn test_max_path_sum_with_path() {
+        assert_eq!(Tree::<i32>::new().max_path_sum_with_path(), None);
+        assert_eq!(Tree::with_root(100).max_path_sum_with_path(), None);
+
+        // Ids in preorder: 5 -> 0, -10 -> 1, 20 -> 2, 25 -> 3, 15 -> 4, 30 -> 5
+        let tree: Tree<i32> = Tree::from_brackets("5(-10(20)(25))(15()(30))").unwrap();
+        assert_eq!(
+            tree.max_path_sum_with_path(),
+            Some((65, vec![3, 1, 0, 4, 5]))
+        );
+
+        // Both leaves of the root give the same branch sum: the left one is taken
+        let tree: Tree<i32> = Tree::from_brackets("0(1(2)(2))(3)").unwrap();
+        assert_eq!(tree.max_path_sum_with_path(), Some((6, vec![2, 1, 0, 4])));
+
+        // Two paths of sum 3: the one whose highest node comes first in postorder wins
+        let tree: Tree<i32> = Tree::from_brackets("-9(0(1)(2))(0(2)(1))").unwrap();
+        assert_eq!(tree.max_path_sum_with_path(), Some((3, vec![2, 1, 3])));
+
+        // The path is made of the ids of the nodes, whatever order they were added in
+        let mut tree = Tree::with_root(1);
+        let right = tree.add_node_right(0, 4).unwrap();
+        let left = tree.add_node_left(0, 2).unwrap();
+        let leaf = tree.add_node_left(left, 3).unwrap();
+        assert_eq!(
+            tree.max_path_sum_with_path(),
+            Some((10, vec![leaf, left, 0, right]))
+        );
+
+        // The path sums to the value of max_path_sum, between two leaves
+        let tree: Tree<i32> = Tree::from_brackets("1(-2(4(3)(-1))(5))(6()(-7(8)(9)))").unwrap();
+        let (sum, path) = tree.max_path_sum_with_path().unwrap();
+        assert_eq!(Some(sum), tree.max_path_sum());
+        assert_eq!(path.iter().map(|&id| tree.nodes[id].key).sum::<i32>(), sum);
+        for id in [path[0], path[path.len() - 1]] {
+            assert!(tree.nodes[id].id_left.is_none() && tree.nodes[id].id_right.is_none());
+        }
+    }
+
     /// Tests the `get_node` method for valid and invalid node IDs.
     #[test]
     fn test_get_node() {
@@ -641,4 +1046,268 @@ mod tests {
         let err3 = tree.add_node_left(42, 1).unwrap_err();
         assert_eq!(err3, "Parent node id does not exist");
     }
+
+    #[test]
+    fn test_deep_tree() {
+        // A linked list of 10^6 nodes, all on the left, ending with two leaves
+        let n = 1_000_000;
+        let mut tree = Tree::with_root(n as i64);
+        let mut id = 0;
+        for key in (1..n as i64).rev() {
+            id = tree.add_node_left(id, key).unwrap();
+        }
+        tree.add_node_left(id, -1).unwrap();
+        tree.add_node_right(id, 0).unwrap();
+
+        assert_eq!(tree.sum(), Some(n as i64 * (n as i64 + 1) / 2 - 1));
+        assert!(!tree.is_bst()); // 0 is the right child of 1
+        assert_eq!(tree.max_path_sum(), Some(0));
+    }
+
+    #[test]
+    fn test_level_order() {
+        let empty: Tree<i32> = Tree::from_level_order(&[]);
+        assert!(empty.nodes.is_empty());
+        assert!(empty.to_level_order().is_empty());
+        assert!(
+            Tree::<i32>::from_level_order(&[None, Some(1)])
+                .nodes
+                .is_empty()
+        );
+
+        let a = [Some(1), None, Some(2), Some(3)];
+        let tree = Tree::from_level_order(&a);
+        assert_eq!(tree.nodes.len(), 3);
+        assert_eq!(tree.nodes[0].id_left, None);
+        assert_eq!(tree.nodes[0].id_right, Some(1));
+        assert_eq!(tree.nodes[1].id_left, Some(2));
+        assert_eq!(tree.to_level_order(), a.to_vec());
+
+        let a = [Some(5), Some(4), Some(8), Some(11), None, Some(13), Some(4)];
+        let tree = Tree::from_level_order(&a);
+        assert_eq!(tree.sum(), Some(45));
+        assert_eq!(tree.to_level_order(), a.to_vec());
+
+        // Trailing `None` are not needed and extra values are ignored
+        let tree = Tree::from_level_order(&[Some(1), Some(2), None, None, None, None, Some(9)]);
+        assert_eq!(tree.to_level_order(), vec![Some(1), Some(2)]);
+    }
+
+    #[test]
+    fn test_brackets() {
+        let empty: Tree<i32> = Tree::from_brackets("  ").unwrap();
+        assert!(empty.nodes.is_empty());
+        assert_eq!(empty.to_brackets(), "");
+
+        for s in [
+            "7",
+            "1(2)",
+            "1()(3)",
+            "1(2(4)(5))(3()(6))",
+            "-1(-2()(-3))(4)",
+        ] {
+            let tree: Tree<i32> = Tree::from_brackets(s).unwrap();
+            assert_eq!(tree.to_brackets(), s);
+        }
+
+        let tree: Tree<i32> = Tree::from_brackets(" 10 ( 5 (3) (7) ) ( 15 () (20) ) ").unwrap();
+        assert!(tree.is_bst());
+        assert_eq!(tree.to_brackets(), "10(5(3)(7))(15()(20))");
+        assert_eq!(
+            tree.to_level_order(),
+            vec![
+                Some(10),
+                Some(5),
+                Some(15),
+                Some(3),
+                Some(7),
+                None,
+                Some(20)
+            ]
+        );
+
+        // Redundant empty left child of a leaf
+        let tree: Tree<i32> = Tree::from_brackets("1()").unwrap();
+        assert_eq!(tree.to_brackets(), "1");
+
+        let tree: Tree<String> = Tree::from_brackets("m(apple)(zebra)").unwrap();
+        assert!(tree.is_bst());
+    }
+
+    /// Tests that printing and parsing again gives back the same tree, also when the
+    /// nodes are not numbered in preorder.
+    #[test]
+    fn test_brackets_round_trip() {
+        let mut tree = Tree::with_root(1);
+        let right = tree.add_node_right(0, -3).unwrap();
+        let left = tree.add_node_left(0, 2).unwrap();
+        tree.add_node_left(right, 6).unwrap();
+        tree.add_node_right(left, 5).unwrap();
+        tree.add_node_right(right, 7).unwrap();
+
+        let s = tree.to_brackets();
+        assert_eq!(s, "1(2()(5))(-3(6)(7))");
+        let parsed: Tree<i32> = Tree::from_brackets(&s).unwrap();
+        assert_eq!(parsed.to_brackets(), s);
+        assert_eq!(parsed.to_level_order(), tree.to_level_order());
+        assert_eq!(
+            Tree::from_level_order(&tree.to_level_order()).to_brackets(),
+            s
+        );
+
+        let empty: Tree<i32> = Tree::new();
+        let parsed: Tree<i32> = Tree::from_brackets(&empty.to_brackets()).unwrap();
+        assert!(parsed.nodes.is_empty());
+    }
+
+    #[test]
+    fn test_brackets_errors() {
+        let parse = |s: &str| Tree::<i32>::from_brackets(s).err();
+        assert_eq!(parse("1(2"), Some("Unbalanced parentheses"));
+        assert_eq!(parse("1(2))"), Some("Unbalanced parentheses"));
+        assert_eq!(parse("1)"), Some("Unbalanced parentheses"));
+        assert_eq!(parse("1(2)(3)(4)"), Some("A node has at most two children"));
+        assert_eq!(parse("(1)"), Some("Missing key"));
+        assert_eq!(parse("1(x)"), Some("Invalid key"));
+        assert_eq!(parse("1(2) 3"), Some("Unexpected key"));
+        // Multi-byte characters are rejected, not sliced through
+        assert_eq!(parse("1(2)é"), Some("Unexpected key"));
+        assert_eq!(parse("1(2)(3)€("), Some("Unexpected key"));
+        assert_eq!(parse("1(é)"), Some("Invalid key"));
+        assert_eq!(parse("ü"), Some("Invalid key"));
+
+        let tree: Tree<String> = Tree::from_brackets("é(ü)(日本)").unwrap();
+        assert_eq!(tree.to_brackets(), "é(ü)(日本)");
+    }
+
+    #[test]
+    fn test_level_order_and_brackets_agree() {
+        let mut tree = Tree::with_root(10);
+        let left = tree.add_node_left(0, 2).unwrap();
+        let right = tree.add_node_right(0, 10).unwrap();
+        tree.add_node_left(left, 20).unwrap();
+        tree.add_node_right(left, 1).unwrap();
+        let right_right = tree.add_node_right(right, -25).unwrap();
+        tree.add_node_left(right_right, 3).unwrap();
+        tree.add_node_right(right_right, 4).unwrap();
+
+        let s = tree.to_brackets();
+        assert_eq!(s, "10(2(20)(1))(10()(-25(3)(4)))");
+        let from_brackets: Tree<i32> = Tree::from_brackets(&s).unwrap();
+        let from_level = Tree::from_level_order(&tree.to_level_order());
+        assert_eq!(from_level.to_brackets(), s);
+        assert_eq!(from_brackets.to_level_order(), tree.to_level_order());
+        assert_eq!(from_brackets.max_path_sum(), Some(23));
+    }
+
+    #[test]
+    fn test_display() {
+        let empty: Tree<i32> = Tree::new();
+        assert_eq!(empty.to_string(), "");
+        assert_eq!(Tree::with_root(7).to_string(), "7\n");
+
+        let tree: Tree<i32> = Tree::from_brackets("1(2(4)(5))(3(6))").unwrap();
+        let expected = ["┌── 3", "│   └── 6", "1", "│   ┌── 5", "└── 2", "    └── 4"];
+        assert_eq!(tree.to_string(), expected.join("\n") + "\n");
+
+        let tree: Tree<i32> = Tree::from_brackets("1()(2(3()(4)))").unwrap();
+        let expected = ["┌── 2", "│   │   ┌── 4", "│   └── 3", "1"];
+        assert_eq!(tree.to_string(), expected.join("\n") + "\n");
+    }
+
+    #[test]
+    fn test_to_dot() {
+        let tree: Tree<i32> = Tree::from_brackets("1(2(4)(5))(3()(6))").unwrap();
+        // Ids in preorder: 1 -> 0, 2 -> 1, 4 -> 2, 5 -> 3, 3 -> 4, 6 -> 5
+        let dot = tree.to_dot(&[2, 1, 0, 4, 5]);
+        assert!(dot.starts_with("digraph tree {"));
+        assert!(dot.ends_with("}\n"));
+        assert!(dot.contains("n2 [label=\"4\", color=red, penwidth=2];"));
+        assert!(dot.contains("n3 [label=\"5\"];"));
+        assert!(dot.contains("n0 -> n1 [color=red, penwidth=2];"));
+        assert!(dot.contains("n1 -> n3;"));
+        // The missing left child of 3 keeps 6 on the right
+        assert!(dot.contains("n4l [style=invis];"));
+        assert_eq!(dot.matches("->").count(), 6);
+
+        // Nodes are named by position in preorder, not by id
+        let mut tree = Tree::with_root(1);
+        let right = tree.add_node_right(0, 3).unwrap();
+        let left = tree.add_node_left(0, 2).unwrap();
+        let dot = tree.to_dot(&[left, 0, right]);
+        assert!(dot.contains("n1 [label=\"2\", color=red, penwidth=2];"));
+        assert!(dot.contains("n0 -> n2 [color=red, penwidth=2];"));
+
+        let tree = Tree::with_root("say \"hi\"");
+        assert!(tree.to_dot(&[]).contains("label=\"say \\\"hi\\\"\""));
+    }
+}
+
+#[cfg(test)]
+mod test_files {
+    use super::*;
+    use std::env::current_dir;
+    use std::fs::read_to_string;
+    use std::path::PathBuf;
+
+    ///
+    /// Internal methods that returns the full path of a test input-output file
+    ///
+    /// All tests are located in the crate_root/test_trees/ folder.
+    ///
+    /// Each `input` file holds the number of trees followed by one tree per line in
+    /// bracket notation. The matching `output` file holds, for each tree, its sum, whether
+    /// it is a BST (`1`/`0`) and its max path sum (`-` if there isn't one).
+    fn test_path(name: &str) -> PathBuf {
+        current_dir().unwrap().join("test_trees").join(name)
+    }
+
+    fn test_files(input_name: &str, output_name: &str) {
+        let input = read_to_string(test_path(input_name)).unwrap();
+        let output = read_to_string(test_path(output_name)).unwrap();
+
+        let mut lines = input.lines();
+        let n: usize = lines.next().unwrap().trim().parse().unwrap();
+        let expected: Vec<&str> = output.lines().collect();
+        assert_eq!(expected.len(), n);
+
+        for (line, expected) in lines.zip(expected) {
+            let tree: Tree<i64> = Tree::from_brackets(line).unwrap();
+            assert_eq!(tree.to_brackets(), line.trim());
+
+            let max_path = tree
+                .max_path_sum()
+                .map_or("-".to_string(), |s| s.to_string());
+            if let Some((sum, path)) = tree.max_path_sum_with_path() {
+                assert_eq!(path.iter().map(|&id| tree.nodes[id].key).sum::<i64>(), sum);
+            }
+            let res = format!(
+                "{} {} {}",
+                tree.sum().unwrap(),
+                tree.is_bst() as u8,
+                max_path
+            );
+            assert_eq!(res, expected, "tree: {line}");
+        }
+    }
+
+    #[test]
+    fn test_0() {
+        test_files("input0.txt", "output0.txt");
+    }
+
+    #[test]
+    fn test_1() {
+        test_files("input1.txt", "output1.txt");
+    }
+
+    #[test]
+    fn test_2() {
+        test_files("input2.txt", "output2.txt");
+    }
+
+    #[test]
+    fn test_3() {
+        test_files("input3.txt", "output3.txt");
+    }
 }
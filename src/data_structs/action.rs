@@ -0,0 +1,204 @@
+use crate::data_structs::monoid::{Bounded, Max, Min, Monoid, Sum};
+use std::marker::PhantomData;
+use std::ops::Mul;
+
+///
+/// An update that can be applied to every element of a range, acting on the aggregates
+/// of a `Monoid`.
+///
+/// Lazy structures store pending updates as tags (`Self::Value`) on internal nodes, so an
+/// action must provide:
+/// - `identity()`: the tag that leaves every value unchanged
+/// - `compose(f, g)`: a single tag equivalent to applying `g` first and then `f`
+/// - `apply(f, x, len)`: the aggregate of a segment of `len` elements whose aggregate was `x`,
+///   after applying `f` to each of its elements
+///
+/// The same action type can act on several monoids (range add works on min, max and sum), so
+/// the monoid is a parameter of the trait.
+///
+pub trait Action<M: Monoid> {
+    type Value: Clone;
+
+    fn identity() -> Self::Value;
+
+    fn compose(f: &Self::Value, g: &Self::Value) -> Self::Value;
+
+    fn apply(f: &Self::Value, x: &M::Value, len: usize) -> M::Value;
+}
+
+/// Implements an action on both `Min` and `Max`, where it has the same definition
+macro_rules! impl_on_min_max {
+    ($action:ident, [$($bound:tt)+], $body:tt) => {
+        impl<T: $($bound)+> Action<Min<T>> for $action<T> $body
+        impl<T: $($bound)+> Action<Max<T>> for $action<T> $body
+    };
+}
+
+///
+/// Converts a segment length to the value type and multiplies it by `v`, used where an update
+/// contributes once per element (actions on `Sum`, the blocks of `SqrtDecomposition`)
+pub(crate) fn scaled<T: Mul<Output = T> + TryFrom<usize>>(v: T, len: usize) -> T {
+    match T::try_from(len) {
+        Ok(l) => v * l,
+        Err(_) => panic!("Segment length not representable"),
+    }
+}
+
+/// `x <- x + v` on every element of the range
+pub struct Add<T>(PhantomData<T>);
+
+impl_on_min_max!(
+    Add,
+    [std::ops::Add<Output = T> + Ord + Copy + Default + Bounded],
+    {
+        type Value = T;
+
+        fn identity() -> T {
+            T::default()
+        }
+
+        fn compose(f: &T, g: &T) -> T {
+            *f + *g
+        }
+
+        fn apply(f: &T, x: &T, _len: usize) -> T {
+            *x + *f
+        }
+    }
+);
+
+impl<T> Action<Sum<T>> for Add<T>
+where
+    T: std::ops::Add<Output = T> + Mul<Output = T> + TryFrom<usize> + Copy + Default,
+{
+    type Value = T;
+
+    fn identity() -> T {
+        T::default()
+    }
+
+    fn compose(f: &T, g: &T) -> T {
+        *f + *g
+    }
+
+    fn apply(f: &T, x: &T, len: usize) -> T {
+        *x + scaled(*f, len)
+    }
+}
+
+/// `x <- v` on every element of the range. `None` is the identity tag
+pub struct Assign<T>(PhantomData<T>);
+
+impl_on_min_max!(Assign, [Ord + Copy + Bounded], {
+    type Value = Option<T>;
+
+    fn identity() -> Option<T> {
+        None
+    }
+
+    fn compose(f: &Option<T>, g: &Option<T>) -> Option<T> {
+        f.or(*g)
+    }
+
+    fn apply(f: &Option<T>, x: &T, _len: usize) -> T {
+        f.unwrap_or(*x)
+    }
+});
+
+impl<T> Action<Sum<T>> for Assign<T>
+where
+    T: std::ops::Add<Output = T> + Mul<Output = T> + TryFrom<usize> + Copy + Default,
+{
+    type Value = Option<T>;
+
+    fn identity() -> Option<T> {
+        None
+    }
+
+    fn compose(f: &Option<T>, g: &Option<T>) -> Option<T> {
+        f.or(*g)
+    }
+
+    fn apply(f: &Option<T>, x: &T, len: usize) -> T {
+        f.map_or(*x, |v| scaled(v, len))
+    }
+}
+
+///
+/// `x <- min(x, v)` on every element of the range. `T::MAX` is the identity tag
+///
+/// It only acts on `Min` and `Max`: keeping a sum consistent requires a Segment Tree Beats
+pub struct Chmin<T>(PhantomData<T>);
+
+impl_on_min_max!(Chmin, [Ord + Copy + Bounded], {
+    type Value = T;
+
+    fn identity() -> T {
+        T::MAX
+    }
+
+    fn compose(f: &T, g: &T) -> T {
+        Ord::min(*f, *g)
+    }
+
+    fn apply(f: &T, x: &T, _len: usize) -> T {
+        Ord::min(*f, *x)
+    }
+});
+
+///
+/// `x <- max(x, v)` on every element of the range. `T::MIN` is the identity tag
+///
+/// It only acts on `Min` and `Max`: keeping a sum consistent requires a Segment Tree Beats
+pub struct Chmax<T>(PhantomData<T>);
+
+impl_on_min_max!(Chmax, [Ord + Copy + Bounded], {
+    type Value = T;
+
+    fn identity() -> T {
+        T::MIN
+    }
+
+    fn compose(f: &T, g: &T) -> T {
+        Ord::max(*f, *g)
+    }
+
+    fn apply(f: &T, x: &T, _len: usize) -> T {
+        Ord::max(*f, *x)
+    }
+});
+
+///
+/// `x <- a * x + b` on every element of the range, the tag is the pair `(a, b)`
+/// and `(1, 0)` is the identity.
+///
+/// It only acts on `Sum`: on `Min` and `Max` a negative `a` swaps the two.
+pub struct Affine<T>(PhantomData<T>);
+
+impl<T> Action<Sum<T>> for Affine<T>
+where
+    T: std::ops::Add<Output = T> + Mul<Output = T> + TryFrom<usize> + Copy + Default,
+{
+    type Value = (T, T);
+
+    fn identity() -> (T, T) {
+        (one(), T::default())
+    }
+
+    fn compose(f: &(T, T), g: &(T, T)) -> (T, T) {
+        // f(g(x)) = fa * (ga * x + gb) + fb
+        (f.0 * g.0, f.0 * g.1 + f.1)
+    }
+
+    fn apply(f: &(T, T), x: &T, len: usize) -> T {
+        f.0 * *x + scaled(f.1, len)
+    }
+}
+
+/// The multiplicative identity, obtained as the length of a single element segment
+fn one<T: TryFrom<usize>>() -> T {
+    match T::try_from(1) {
+        Ok(v) => v,
+        Err(_) => panic!("Value type can't represent 1"),
+    }
+}
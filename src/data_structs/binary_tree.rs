@@ -0,0 +1,656 @@
+use crate::data_structs::tree::TreeNode;
+use hands_on_1::{Node, Tree};
+use std::collections::{HashSet, VecDeque};
+use std::fmt::{self, Display, Formatter};
+use std::iter::successors;
+use std::ops::Add;
+
+///
+/// ## Binary Tree
+///
+/// Read-only view of a binary tree, so that the same algorithm runs on any representation:
+/// - `TreeNode`: boxed nodes, a node is identified by a reference to it
+/// - `hands_on_1::Tree`: nodes in an arena, a node is identified by its index (the root is `0`)
+///
+pub trait BinaryTree {
+    type Value;
+    type Id<'a>: Copy
+    where
+        Self: 'a;
+
+    /// The root, `None` if the tree is empty
+    fn root(&self) -> Option<Self::Id<'_>>;
+
+    fn left<'a>(&'a self, id: Self::Id<'a>) -> Option<Self::Id<'a>>;
+
+    fn right<'a>(&'a self, id: Self::Id<'a>) -> Option<Self::Id<'a>>;
+
+    fn value<'a>(&'a self, id: Self::Id<'a>) -> &'a Self::Value;
+
+    /// A number that tells a node apart from the others of the tree: its index in the arena,
+    /// the address of the node for boxed nodes
+    fn index<'a>(&'a self, id: Self::Id<'a>) -> usize;
+
+    /// Nodes in preorder (node, left subtree, right subtree)
+    fn preorder(&self) -> Preorder<'_, Self>
+    where
+        Self: Sized,
+    {
+        Preorder {
+            tree: self,
+            stack: self.root().into_iter().collect(),
+        }
+    }
+
+    /// Nodes in inorder (left subtree, node, right subtree)
+    fn inorder(&self) -> Inorder<'_, Self>
+    where
+        Self: Sized,
+    {
+        Inorder {
+            tree: self,
+            stack: vec![],
+            next: self.root(),
+        }
+    }
+
+    /// Nodes in postorder (left subtree, right subtree, node)
+    fn postorder(&self) -> Postorder<'_, Self>
+    where
+        Self: Sized,
+    {
+        Postorder {
+            tree: self,
+            stack: self.root().map(|r| (r, false)).into_iter().collect(),
+        }
+    }
+
+    /// Nodes by increasing depth, from left to right
+    fn level_order(&self) -> LevelOrder<'_, Self>
+    where
+        Self: Sized,
+    {
+        LevelOrder {
+            tree: self,
+            queue: self.root().into_iter().collect(),
+        }
+    }
+}
+
+impl<T> BinaryTree for TreeNode<T> {
+    type Value = T;
+    type Id<'a>
+        = &'a TreeNode<T>
+    where
+        T: 'a;
+
+    fn root(&self) -> Option<&TreeNode<T>> {
+        Some(self)
+    }
+
+    fn left<'a>(&'a self, id: &'a TreeNode<T>) -> Option<&'a TreeNode<T>> {
+        id.left.as_deref()
+    }
+
+    fn right<'a>(&'a self, id: &'a TreeNode<T>) -> Option<&'a TreeNode<T>> {
+        id.right.as_deref()
+    }
+
+    fn value<'a>(&'a self, id: &'a TreeNode<T>) -> &'a T {
+        &id.val
+    }
+
+    fn index<'a>(&'a self, id: &'a TreeNode<T>) -> usize {
+        id as *const TreeNode<T> as usize
+    }
+}
+
+impl<T> BinaryTree for Tree<T> {
+    type Value = T;
+    type Id<'a>
+        = usize
+    where
+        T: 'a;
+
+    fn root(&self) -> Option<usize> {
+        if self.nodes.is_empty() { None } else { Some(0) }
+    }
+
+    fn left(&self, id: usize) -> Option<usize> {
+        self.nodes[id].id_left
+    }
+
+    fn right(&self, id: usize) -> Option<usize> {
+        self.nodes[id].id_right
+    }
+
+    fn value(&self, id: usize) -> &T {
+        &self.nodes[id].key
+    }
+
+    fn index(&self, id: usize) -> usize {
+        id
+    }
+}
+
+/* TRAVERSALS */
+
+///
+/// Preorder iterator over the ids of a `BinaryTree`. Like the other traversals it keeps an
+/// explicit stack instead of recursing, so it works on trees of any depth.
+pub struct Preorder<'a, B: BinaryTree> {
+    tree: &'a B,
+    stack: Vec<B::Id<'a>>,
+}
+
+impl<'a, B: BinaryTree> Iterator for Preorder<'a, B> {
+    type Item = B::Id<'a>;
+
+    fn next(&mut self) -> Option<B::Id<'a>> {
+        let id = self.stack.pop()?;
+        //the right child is pushed first, so the left subtree is visited before it
+        self.stack.extend(self.tree.right(id));
+        self.stack.extend(self.tree.left(id));
+        Some(id)
+    }
+}
+
+///
+/// Inorder iterator over the ids of a `BinaryTree`: the stack holds the nodes whose left
+/// subtree is being visited
+pub struct Inorder<'a, B: BinaryTree> {
+    tree: &'a B,
+    stack: Vec<B::Id<'a>>,
+    next: Option<B::Id<'a>>,
+}
+
+impl<'a, B: BinaryTree> Iterator for Inorder<'a, B> {
+    type Item = B::Id<'a>;
+
+    fn next(&mut self) -> Option<B::Id<'a>> {
+        //go down to the leftmost node of the subtree still to visit
+        while let Some(id) = self.next {
+            self.stack.push(id);
+            self.next = self.tree.left(id);
+        }
+        let id = self.stack.pop()?;
+        self.next = self.tree.right(id);
+        Some(id)
+    }
+}
+
+///
+/// Postorder iterator over the ids of a `BinaryTree`: every node is on the stack with a
+/// flag telling if its children were already pushed above it
+pub struct Postorder<'a, B: BinaryTree> {
+    tree: &'a B,
+    stack: Vec<(B::Id<'a>, bool)>,
+}
+
+impl<'a, B: BinaryTree> Iterator for Postorder<'a, B> {
+    type Item = B::Id<'a>;
+
+    fn next(&mut self) -> Option<B::Id<'a>> {
+        loop {
+            let (id, expanded) = self.stack.pop()?;
+            if expanded {
+                return Some(id);
+            }
+            self.stack.push((id, true));
+            self.stack.extend(self.tree.right(id).map(|r| (r, false)));
+            self.stack.extend(self.tree.left(id).map(|l| (l, false)));
+        }
+    }
+}
+
+///
+/// Level order (BFS) iterator over the ids of a `BinaryTree`
+pub struct LevelOrder<'a, B: BinaryTree> {
+    tree: &'a B,
+    queue: VecDeque<B::Id<'a>>,
+}
+
+impl<'a, B: BinaryTree> Iterator for LevelOrder<'a, B> {
+    type Item = B::Id<'a>;
+
+    fn next(&mut self) -> Option<B::Id<'a>> {
+        let id = self.queue.pop_front()?;
+        self.queue.extend(self.tree.left(id));
+        self.queue.extend(self.tree.right(id));
+        Some(id)
+    }
+}
+
+///
+/// Bottom-up evaluation of a tree without recursion: `f` receives a node with the results
+/// of its left and right subtrees (if present), and the result of the root is returned.
+///
+/// Nodes come in postorder, so when a node is visited the results of its children are the
+/// last ones computed: the right one on top of a stack, the left one below it.
+///
+/// ## Returns
+/// `None` if the tree is empty
+pub fn fold_postorder<'a, B, S>(
+    t: &'a B,
+    mut f: impl FnMut(B::Id<'a>, Option<S>, Option<S>) -> S,
+) -> Option<S>
+where
+    B: BinaryTree,
+{
+    let mut results: Vec<S> = vec![];
+    for id in t.postorder() {
+        let r = t.right(id).map(|_| results.pop().unwrap());
+        let l = t.left(id).map(|_| results.pop().unwrap());
+        results.push(f(id, l, r));
+    }
+    results.pop()
+}
+
+/* CONVERSIONS */
+
+///
+/// Moves the nodes of a `TreeNode` in an arena, in preorder: the root gets the index `0`
+/// as `hands_on_1::Tree` expects
+///
+/// `TreeNode` implements `Drop`, so its fields are taken instead of moved: the value is
+/// replaced with its default
+impl<T: Default> From<TreeNode<T>> for Tree<T> {
+    fn from(t: TreeNode<T>) -> Self {
+        let mut nodes: Vec<Node<T>> = vec![];
+        //(subtree, parent id and whether it is its left child)
+        let mut stack = vec![(t, None::<(usize, bool)>)];
+        while let Some((mut node, parent)) = stack.pop() {
+            let (left, right) = (node.left.take(), node.right.take());
+            let id = nodes.len();
+            nodes.push(Node::new(std::mem::take(&mut node.val)));
+            match parent {
+                Some((p, true)) => nodes[p].id_left = Some(id),
+                Some((p, false)) => nodes[p].id_right = Some(id),
+                None => {}
+            }
+            stack.extend(right.map(|r| (*r, Some((id, false)))));
+            stack.extend(left.map(|l| (*l, Some((id, true)))));
+        }
+        Tree { nodes }
+    }
+}
+
+///
+/// Rebuilds the boxed nodes of the tree rooted at index `0`. Only the indices are lost:
+/// converting back yields the same tree with the nodes numbered in preorder.
+impl<T> TryFrom<Tree<T>> for TreeNode<T> {
+    type Error = &'static str;
+
+    fn try_from(t: Tree<T>) -> Result<Self, Self::Error> {
+        if t.nodes.is_empty() {
+            return Err("Empty tree");
+        }
+        //children are built before their parent
+        let order: Vec<usize> = t.postorder().collect();
+        let mut slots: Vec<Option<Node<T>>> = t.nodes.into_iter().map(Some).collect();
+        let mut built: Vec<Option<TreeNode<T>>> = slots.iter().map(|_| None).collect();
+        for id in order {
+            let node = slots[id].take().expect("Node reachable twice");
+            let mut child = |c: Option<usize>| c.map(|c| Box::new(built[c].take().unwrap()));
+            let (left, right) = (child(node.id_left), child(node.id_right));
+            built[id] = Some(TreeNode {
+                val: node.key,
+                left,
+                right,
+            });
+        }
+        Ok(built[0].take().unwrap())
+    }
+}
+
+/* SERIALIZATION */
+
+///
+/// Level order representation of a tree (as used by LeetCode): nodes by increasing depth,
+/// with `None` for the missing children of every node and no trailing `None`.
+///
+/// The inverse of `hands_on_1::Tree::from_level_order`, on both representations
+pub fn to_level_order<B>(t: &B) -> Vec<Option<B::Value>>
+where
+    B: BinaryTree,
+    B::Value: Clone,
+{
+    let mut res = vec![];
+    let mut queue: VecDeque<_> = t.root().map(Some).into_iter().collect();
+    while let Some(slot) = queue.pop_front() {
+        res.push(slot.map(|id| t.value(id).clone()));
+        if let Some(id) = slot {
+            queue.extend([t.left(id), t.right(id)]);
+        }
+    }
+    while let Some(None) = res.last() {
+        res.pop();
+    }
+    res
+}
+
+///
+/// Bracket notation of a tree: a node is its value followed by the subtrees of its
+/// children between parentheses, a missing left child is `()` and a missing right child
+/// is omitted (e.g. `1(2(4)(5))(3()(6))`).
+///
+/// The inverse of `hands_on_1::Tree::from_brackets`, on both representations
+pub fn to_brackets<B>(t: &B) -> String
+where
+    B: BinaryTree,
+    B::Value: Display,
+{
+    //either a node still to print or a piece of text
+    enum Item<Id> {
+        Node(Id),
+        Text(&'static str),
+    }
+
+    let mut res = String::new();
+    let mut stack: Vec<_> = t.root().map(Item::Node).into_iter().collect();
+    while let Some(item) = stack.pop() {
+        match item {
+            Item::Text(s) => res.push_str(s),
+            Item::Node(id) => {
+                res.push_str(&t.value(id).to_string());
+                //pushed in reverse order
+                let (l, r) = (t.left(id), t.right(id));
+                if let Some(r) = r {
+                    stack.extend([Item::Text(")"), Item::Node(r), Item::Text("(")]);
+                }
+                match l {
+                    Some(l) => stack.extend([Item::Text(")"), Item::Node(l), Item::Text("(")]),
+                    None if r.is_some() => stack.push(Item::Text("()")),
+                    None => {}
+                }
+            }
+        }
+    }
+    res
+}
+
+/* RENDERING */
+
+///
+/// Draws the tree sideways with box-drawing characters: the root on the first column, right
+/// subtrees above their parent and left subtrees below it
+///
+/// ```text
+/// ┌── 3
+/// │   └── 6
+/// 1
+/// │   ┌── 5
+/// └── 2
+///     └── 4
+/// ```
+pub fn render<B>(t: &B, f: &mut Formatter<'_>) -> fmt::Result
+where
+    B: BinaryTree,
+    B::Value: Display,
+{
+    //a node still to draw with the prefix of its lines and its side (None for the root),
+    //or a line already built
+    enum Item<Id> {
+        Node(Id, String, Option<bool>),
+        Line(String),
+    }
+
+    let mut stack: Vec<_> = t
+        .root()
+        .map(|root| Item::Node(root, String::new(), None))
+        .into_iter()
+        .collect();
+    while let Some(item) = stack.pop() {
+        match item {
+            Item::Line(line) => writeln!(f, "{line}")?,
+            Item::Node(id, prefix, side) => {
+                //(connector, prefix of the right subtree, prefix of the left subtree)
+                let (line, above, below) = match side {
+                    None => (String::new(), "", ""),
+                    Some(true) => (format!("{prefix}└── "), "│   ", "    "),
+                    Some(false) => (format!("{prefix}┌── "), "    ", "│   "),
+                };
+                //pushed in reverse order: right subtree, node, left subtree
+                if let Some(l) = t.left(id) {
+                    stack.push(Item::Node(l, format!("{prefix}{below}"), Some(true)));
+                }
+                stack.push(Item::Line(format!("{line}{}", t.value(id))));
+                if let Some(r) = t.right(id) {
+                    stack.push(Item::Node(r, format!("{prefix}{above}"), Some(false)));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+///
+/// `Display` adapter that draws any `BinaryTree` with `render`, e.g. `Render(&tree).to_string()`
+/// (`hands_on_1::Tree` also implements `Display` itself, with the same layout)
+pub struct Render<'a, B>(pub &'a B);
+
+impl<B> Display for Render<'_, B>
+where
+    B: BinaryTree,
+    B::Value: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        render(self.0, f)
+    }
+}
+
+///
+/// The tree in the Graphviz DOT language, nodes are named by their position in preorder.
+///
+/// The nodes in `path` and the edges between consecutive nodes of `path` are highlighted,
+/// e.g. the path found by `max_path_sum_with_path`. An invisible child keeps a single child
+/// on its side.
+pub fn to_dot<'a, B>(t: &'a B, path: &[B::Id<'a>]) -> String
+where
+    B: BinaryTree,
+    B::Value: Display,
+{
+    const HIGHLIGHT: &str = "color=red, penwidth=2";
+    let path: Vec<usize> = path.iter().map(|&id| t.index(id)).collect();
+    let on_path: HashSet<usize> = path.iter().copied().collect();
+    let edges: HashSet<(usize, usize)> = path
+        .windows(2)
+        .flat_map(|w| [(w[0], w[1]), (w[1], w[0])])
+        .collect();
+
+    //preorder traversal that records the positions of the children of every node
+    let mut indices = vec![];
+    let mut values = vec![];
+    let mut children: Vec<[Option<usize>; 2]> = vec![];
+    let mut stack: Vec<_> = t
+        .root()
+        .map(|root| (root, None::<(usize, usize)>))
+        .into_iter()
+        .collect();
+    while let Some((id, parent)) = stack.pop() {
+        let i = values.len();
+        indices.push(t.index(id));
+        values.push(t.value(id));
+        children.push([None, None]);
+        if let Some((p, side)) = parent {
+            children[p][side] = Some(i);
+        }
+        stack.extend(t.right(id).map(|r| (r, Some((i, 1)))));
+        stack.extend(t.left(id).map(|l| (l, Some((i, 0)))));
+    }
+
+    let mut res = String::from("digraph tree {\n    node [shape=circle];\n");
+    for (i, (value, &[left, right])) in values.iter().zip(&children).enumerate() {
+        let has_child = left.is_some() || right.is_some();
+        let label = value.to_string().replace('\\', "\\\\").replace('"', "\\\"");
+        let style = if on_path.contains(&indices[i]) {
+            format!(", {HIGHLIGHT}")
+        } else {
+            String::new()
+        };
+        res.push_str(&format!("    n{i} [label=\"{label}\"{style}];\n"));
+
+        for (child, side) in [(left, "l"), (right, "r")] {
+            match child {
+                Some(c) => {
+                    let style = if edges.contains(&(indices[i], indices[c])) {
+                        format!(" [{HIGHLIGHT}]")
+                    } else {
+                        String::new()
+                    };
+                    res.push_str(&format!("    n{i} -> n{c}{style};\n"));
+                }
+                //placeholder for the missing side of a node with one child
+                None if has_child => {
+                    res.push_str(&format!("    n{i}{side} [style=invis];\n"));
+                    res.push_str(&format!("    n{i} -> n{i}{side} [style=invis];\n"));
+                }
+                None => {}
+            }
+        }
+    }
+    res.push_str("}\n");
+    res
+}
+
+/* ALGORITHMS */
+
+///
+/// Sum of all the values of the tree
+///
+/// ## Returns
+/// `None` if the tree is empty
+pub fn sum<B>(t: &B) -> Option<B::Value>
+where
+    B: BinaryTree,
+    B::Value: Add<Output = B::Value> + Copy,
+{
+    t.preorder().map(|id| *t.value(id)).reduce(|acc, v| acc + v)
+}
+
+///
+/// Checks the Binary Search Tree property: every value in the left subtree of a node is
+/// `<` than its value, every value in the right subtree is `>=`
+///
+/// An empty tree is a BST
+pub fn is_bst<B>(t: &B) -> bool
+where
+    B: BinaryTree,
+    B::Value: Ord,
+{
+    //(min, max) of every valid subtree, None as soon as a subtree is not a BST
+    let res = fold_postorder(t, |id, l, r| {
+        let v = t.value(id);
+        let (min, max) = match (l, r) {
+            (Some(None), _) | (_, Some(None)) => return None,
+            (l, r) => (l.flatten(), r.flatten()),
+        };
+        if min.is_some_and(|(_, l_max)| l_max >= v) || max.is_some_and(|(r_min, _)| r_min < v) {
+            return None;
+        }
+        Some((
+            min.map_or(v, |(l_min, _)| l_min),
+            max.map_or(v, |(_, r_max)| r_max),
+        ))
+    });
+    res.is_none_or(|bounds| bounds.is_some())
+}
+
+///
+/// Max sum of a path between two special nodes, i.e. nodes connected to exactly one other
+/// node: the leaves, and the root when it has a single child (see
+/// `mandatory::maximum_path_sum` for the strategy)
+///
+/// ## Returns
+/// `None` if the tree has less than two nodes
+pub fn max_path_sum<B>(t: &B) -> Option<B::Value>
+where
+    B: BinaryTree,
+    B::Value: Add<Output = B::Value> + Ord + Copy,
+{
+    //(max path from a leaf to the node, max path sum between two leaves of the subtree)
+    let (path, sum) = fold_postorder(t, |id, l, r| {
+        let v = *t.value(id);
+        match (l, r) {
+            (None, None) => (v, None),
+            (Some((path, sum)), None) | (None, Some((path, sum))) => (path + v, sum),
+            (Some((path_l, sum_l)), Some((path_r, sum_r))) => {
+                let sum = [sum_l, sum_r]
+                    .into_iter()
+                    .flatten()
+                    .fold(path_l + path_r + v, Ord::max);
+                (path_l.max(path_r) + v, Some(sum))
+            }
+        }
+    })?;
+
+    let root = t.root()?;
+    if t.left(root).is_some() != t.right(root).is_some() {
+        //the root is a special node too: path goes from a leaf to it
+        Some(sum.map_or(path, |s| s.max(path)))
+    } else {
+        sum
+    }
+}
+
+///
+/// Same as `max_path_sum`, also returning the path that achieves the max sum: the ids of its
+/// nodes, from the end in the left subtree of its highest node to the end in the right one
+/// (a root with a single child is the highest node of its path)
+///
+/// Ties are broken deterministically: among paths with the same sum the one whose highest node
+/// comes first in postorder wins, and a path goes down to the left child when both children
+/// lead to the same sum. On a tree whose root is not special the path is the one of
+/// `hands_on_1::Tree::max_path_sum_with_path`
+///
+/// ## Returns
+/// `None` if the tree has less than two nodes
+pub fn max_path_sum_with_path<B>(t: &B) -> Option<(B::Value, Vec<B::Id<'_>>)>
+where
+    B: BinaryTree,
+    B::Value: Add<Output = B::Value> + Ord + Copy,
+{
+    //nodes by postorder position: id, positions of the children, child on the max path from
+    //a leaf
+    let mut ids = vec![];
+    let mut children: Vec<[Option<usize>; 2]> = vec![];
+    let mut down: Vec<Option<usize>> = vec![];
+    //(sum, position of the highest node) of the best path
+    let mut best: Option<(B::Value, usize)> = None;
+    let (path, root) = fold_postorder(t, |id, l: Option<(B::Value, usize)>, r| {
+        let (i, v) = (ids.len(), *t.value(id));
+        ids.push(id);
+        children.push([l.map(|(_, c)| c), r.map(|(_, c)| c)]);
+        let (path, child) = match (l, r) {
+            (None, None) => (v, None),
+            (Some((path, c)), None) | (None, Some((path, c))) => (path + v, Some(c)),
+            (Some((path_l, c_l)), Some((path_r, c_r))) => {
+                let sum = path_l + path_r + v;
+                if best.is_none_or(|(s, _)| sum > s) {
+                    best = Some((sum, i));
+                }
+                if path_r > path_l {
+                    (path_r + v, Some(c_r))
+                } else {
+                    (path_l + v, Some(c_l))
+                }
+            }
+        };
+        down.push(child);
+        (path, i)
+    })?;
+
+    if children[root].iter().flatten().count() == 1 && best.is_none_or(|(s, _)| path > s) {
+        //the root is a special node too: path goes from a leaf to it
+        best = Some((path, root));
+    }
+
+    let (sum, top) = best?;
+    let branch = |start: Option<usize>| successors(start, |&i| down[i]).map(|i| ids[i]);
+    let [left, right] = children[top];
+    let mut res: Vec<_> = branch(left).collect();
+    res.reverse();
+    res.push(ids[top]);
+    res.extend(branch(right));
+    Some((sum, res))
+}
@@ -0,0 +1,249 @@
+///
+/// ## Disjoint Set Union (Union-Find)
+///
+/// Partition of the elements `0..n` in disjoint components, initially one per element, that
+/// supports merging two components and finding the component of an element.
+///
+/// Every component is a tree whose root is its representative:
+/// - **union by rank**: the root of lower rank (an upper bound of the height) is attached to
+///   the other one, so trees stay logarithmic
+/// - **path compression**: `find` attaches every visited element directly to the root
+///
+/// ### Complexity
+/// `O(n)` space, `O(α(n))` amortized per operation (inverse Ackermann, constant in practice).
+///
+pub struct Dsu {
+    parent: Vec<usize>,
+    rank: Vec<u32>,
+    size: Vec<usize>,
+    components: usize,
+}
+
+impl Dsu {
+    pub fn new(n: usize) -> Self {
+        Self {
+            parent: (0..n).collect(),
+            rank: vec![0; n],
+            size: vec![1; n],
+            components: n,
+        }
+    }
+
+    /// Number of elements
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// Number of components
+    pub fn count(&self) -> usize {
+        self.components
+    }
+
+    /* PUBLIC API */
+    /// Representative of the component of `x`
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        //second pass: compress the path
+        let mut x = x;
+        while self.parent[x] != root {
+            let next = self.parent[x];
+            self.parent[x] = root;
+            x = next;
+        }
+        root
+    }
+
+    /* PUBLIC API */
+    ///
+    /// Merges the components of `a` and `b`
+    ///
+    /// ## Returns
+    /// `false` if they were already the same component
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        if self.rank[a] < self.rank[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+        if self.rank[a] == self.rank[b] {
+            self.rank[a] += 1;
+        }
+        self.components -= 1;
+        true
+    }
+
+    /* PUBLIC API */
+    pub fn same(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /* PUBLIC API */
+    /// Number of elements in the component of `x`
+    pub fn size(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.size[root]
+    }
+
+    /* PUBLIC API */
+    ///
+    /// Iterates over the components, each one as the sorted list of its elements. Components
+    /// are ordered by their smallest element.
+    pub fn components(&mut self) -> impl Iterator<Item = Vec<usize>> + use<> {
+        let mut groups: Vec<Vec<usize>> = vec![vec![]; self.len()];
+        for x in 0..self.len() {
+            let root = self.find(x);
+            groups[root].push(x);
+        }
+        groups.sort_unstable_by_key(|g| g.first().copied().unwrap_or(usize::MAX));
+        groups.into_iter().filter(|g| !g.is_empty())
+    }
+}
+
+///
+/// ## Disjoint Set Union with rollback
+///
+/// Same as `Dsu`, but unions can be undone in reverse order, as needed by offline algorithms
+/// that explore a history of unions (e.g. dynamic connectivity on a segment tree over time).
+///
+/// Path compression would rewrite many parents at every `find`, so it is not used: with
+/// union by rank alone trees have height `O(log(n))`, and a union changes a single parent
+/// that we store in a history stack.
+///
+/// ### Complexity
+/// `O(n + u)` space with `u` unions, `O(log(n))` per `find` and `union`, `O(1)` to undo a union.
+///
+pub struct RollbackDsu {
+    parent: Vec<usize>,
+    rank: Vec<u32>,
+    size: Vec<usize>,
+    components: usize,
+    //for each union: the root that was attached and whether the rank of the other root grew
+    history: Vec<Option<(usize, bool)>>,
+}
+
+impl RollbackDsu {
+    pub fn new(n: usize) -> Self {
+        Self {
+            parent: (0..n).collect(),
+            rank: vec![0; n],
+            size: vec![1; n],
+            components: n,
+            history: vec![],
+        }
+    }
+
+    /// Number of elements
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// Number of components
+    pub fn count(&self) -> usize {
+        self.components
+    }
+
+    /* PUBLIC API */
+    /// Representative of the component of `x`
+    pub fn find(&self, x: usize) -> usize {
+        let mut x = x;
+        while self.parent[x] != x {
+            x = self.parent[x];
+        }
+        x
+    }
+
+    /* PUBLIC API */
+    ///
+    /// Merges the components of `a` and `b`. Every call (even without a merge) is recorded,
+    /// so it can be undone by `rollback`.
+    ///
+    /// ## Returns
+    /// `false` if they were already the same component
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            self.history.push(None);
+            return false;
+        }
+        if self.rank[a] < self.rank[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        let grows = self.rank[a] == self.rank[b];
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+        if grows {
+            self.rank[a] += 1;
+        }
+        self.components -= 1;
+        self.history.push(Some((b, grows)));
+        true
+    }
+
+    /* PUBLIC API */
+    pub fn same(&self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /* PUBLIC API */
+    /// Number of elements in the component of `x`
+    pub fn size(&self, x: usize) -> usize {
+        self.size[self.find(x)]
+    }
+
+    /* PUBLIC API */
+    /// Number of unions recorded so far, to be passed to `rollback`
+    pub fn snapshot(&self) -> usize {
+        self.history.len()
+    }
+
+    /* PUBLIC API */
+    ///
+    /// Undoes the unions performed after `snapshot`, in reverse order
+    ///
+    /// ## Panics
+    /// If `snapshot` is greater than the number of recorded unions
+    pub fn rollback(&mut self, snapshot: usize) {
+        if snapshot > self.history.len() {
+            panic!("Snapshot not recorded")
+        }
+        while self.history.len() > snapshot {
+            if let Some((b, grows)) = self.history.pop().unwrap() {
+                let a = self.parent[b];
+                self.parent[b] = b;
+                self.size[a] -= self.size[b];
+                if grows {
+                    self.rank[a] -= 1;
+                }
+                self.components += 1;
+            }
+        }
+    }
+
+    /* PUBLIC API */
+    ///
+    /// Iterates over the components, each one as the sorted list of its elements. Components
+    /// are ordered by their smallest element.
+    pub fn components(&self) -> impl Iterator<Item = Vec<usize>> + use<> {
+        let mut groups: Vec<Vec<usize>> = vec![vec![]; self.len()];
+        for x in 0..self.len() {
+            groups[self.find(x)].push(x);
+        }
+        groups.sort_unstable_by_key(|g| g.first().copied().unwrap_or(usize::MAX));
+        groups.into_iter().filter(|g| !g.is_empty())
+    }
+}
@@ -0,0 +1,150 @@
+use crate::data_structs::monoid::Monoid;
+
+/// Sentinel for a child that was never allocated
+const NONE: usize = usize::MAX;
+
+///
+/// Node of a `DynamicSegmentTree`, children are indices in the node arena
+struct Node<V> {
+    val: V,
+    left: usize,
+    right: usize,
+}
+
+///
+/// ## Dynamic (sparse) Segment Tree
+///
+/// Segment tree generic over a `Monoid` whose leaves are the `i64` keys of a range `[lo,hi]`
+/// that can be huge (up to the whole `i64` domain). Every key initially holds the identity.
+///
+/// Nodes are allocated only when an update walks through them, a missing child stands for
+/// a subtree of identities, so queries simply skip it.
+///
+/// ### Complexity
+/// Given `C = hi - lo + 1` the height of the tree is `O(log(C))`: updates and queries cost
+/// `O(log(C))` monoid operations and every update allocates at most `O(log(C))` nodes. So
+/// `q` updates take `O(q log(C))` memory, independently from the size of the key range.
+///
+pub struct DynamicSegmentTree<M: Monoid> {
+    lo: i64,
+    hi: i64,
+    nodes: Vec<Node<M::Value>>,
+}
+
+impl<M: Monoid> DynamicSegmentTree<M> {
+    ///
+    /// Creates a tree over the keys `[lo,hi]` where every key holds the identity
+    ///
+    /// ## Panics
+    /// If `lo > hi`
+    pub fn new(lo: i64, hi: i64) -> Self {
+        if lo > hi {
+            panic!("Range not supported")
+        }
+        Self {
+            lo,
+            hi,
+            nodes: vec![Node {
+                val: M::identity(),
+                left: NONE,
+                right: NONE,
+            }],
+        }
+    }
+
+    /// Number of allocated nodes
+    pub fn nodes(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Floor of the midpoint of `[left,right]`, without overflowing on the whole `i64` range
+    fn mid(left: i64, right: i64) -> i64 {
+        (left >> 1) + (right >> 1) + (left & right & 1)
+    }
+
+    fn value(&self, idx: usize) -> M::Value {
+        if idx == NONE {
+            M::identity()
+        } else {
+            self.nodes[idx].val.clone()
+        }
+    }
+
+    /// Returns the child, allocating it if it doesn't exist yet
+    fn child(&mut self, idx: usize, is_left: bool) -> usize {
+        let existing = if is_left {
+            self.nodes[idx].left
+        } else {
+            self.nodes[idx].right
+        };
+        if existing != NONE {
+            return existing;
+        }
+        self.nodes.push(Node {
+            val: M::identity(),
+            left: NONE,
+            right: NONE,
+        });
+        let created = self.nodes.len() - 1;
+        if is_left {
+            self.nodes[idx].left = created;
+        } else {
+            self.nodes[idx].right = created;
+        }
+        created
+    }
+
+    fn set_rec(&mut self, idx: usize, left: i64, right: i64, key: i64, val: M::Value) {
+        if left == right {
+            self.nodes[idx].val = val;
+            return;
+        }
+        let mid = Self::mid(left, right);
+        if key <= mid {
+            let c = self.child(idx, true);
+            self.set_rec(c, left, mid, key, val);
+        } else {
+            let c = self.child(idx, false);
+            self.set_rec(c, mid + 1, right, key, val);
+        }
+        let (l, r) = (self.nodes[idx].left, self.nodes[idx].right);
+        self.nodes[idx].val = M::combine(&self.value(l), &self.value(r));
+    }
+
+    fn query_rec(&self, idx: usize, left: i64, right: i64, qleft: i64, qright: i64) -> M::Value {
+        if idx == NONE || qleft > right || qright < left {
+            M::identity() //missing subtree or no overlap
+        } else if qleft <= left && qright >= right {
+            self.nodes[idx].val.clone() //total overlap
+        } else {
+            //partial overlap
+            let mid = Self::mid(left, right);
+            let node = &self.nodes[idx];
+            let v_left = self.query_rec(node.left, left, mid, qleft, qright);
+            let v_right = self.query_rec(node.right, mid + 1, right, qleft, qright);
+            M::combine(&v_left, &v_right)
+        }
+    }
+
+    /* PUBLIC API */
+    pub fn set(&mut self, key: i64, val: M::Value) {
+        if key < self.lo || key > self.hi {
+            panic!("Key out of bounds")
+        }
+        self.set_rec(0, self.lo, self.hi, key, val)
+    }
+
+    /* PUBLIC API */
+    pub fn get(&self, key: i64) -> M::Value {
+        self.query(key, key)
+    }
+
+    /* PUBLIC API */
+    ///
+    /// Aggregate of the keys in `[qleft,qright]`
+    ///
+    /// The range is clamped to the keys of the tree, an empty range returns the identity
+    pub fn query(&self, qleft: i64, qright: i64) -> M::Value {
+        self.query_rec(0, self.lo, self.hi, qleft, qright)
+    }
+}
@@ -20,13 +20,20 @@ where
         self.len() == 0
     }
 
+    ///
+    /// Builds the tree from a slice in linear time
+    ///
+    /// Every node `i` stores the sum of the `isolate_trailing_one(i)` items ending at `i`
+    /// (1-indexed). We copy the items in place, then each node, once complete, pushes its
+    /// partial sum to the next node that covers it (`next_sibling(i)`).
     pub fn from(slice: &[T]) -> Self {
         let mut v = Vec::with_capacity(slice.len() + 1);
         v.push(T::default()); //we'll never use it
-        if !slice.is_empty() {
-            v.push(slice[0]);
-            for i in 1..slice.len() {
-                v.push(slice[i] + v[i - 1])
+        v.extend_from_slice(slice);
+        for i in 1..v.len() {
+            let j = Self::next_sibling(i);
+            if j < v.len() {
+                v[j] = v[j] + v[i];
             }
         }
         Self { tree: v }
@@ -37,24 +44,44 @@ where
     }
 
     pub fn add(&mut self, i: usize, delta: T) -> Result<(), &str> {
+        self.update(i, |x| x + delta)
+    }
+
+    /// Applies `f` to all the nodes covering the item at `i`
+    fn update(&mut self, i: usize, f: impl Fn(T) -> T) -> Result<(), &str> {
         let mut i = i + 1;
         if i >= self.tree.len() {
             return Err("Index overflow");
         }
         while i < self.tree.len() {
-            self.tree[i] = self.tree[i] + delta;
+            self.tree[i] = f(self.tree[i]);
             i = Self::next_sibling(i);
         }
         Ok(())
     }
 
     pub fn access(&self, i: usize) -> Result<T, &str> {
-        let i = i + 1;
-        Ok(if i == 0 {
-            self.tree[0]
+        if i == 0 {
+            self.sum(0)
         } else {
-            self.sum(i)? - self.sum(i - 1)?
-        })
+            Ok(self.sum(i)? - self.sum(i - 1)?)
+        }
+    }
+
+    ///## Set
+    /// Replaces the item at `i` adding the difference with the current value
+    ///
+    /// When the value decreases the difference is subtracted instead, so that it never goes
+    /// below zero on unsigned types (e.g. counts)
+    pub fn set(&mut self, i: usize, v: T) -> Result<(), &str>
+    where
+        T: PartialOrd,
+    {
+        match self.access(i) {
+            Ok(old) if v >= old => self.add(i, v - old),
+            Ok(old) => self.update(i, |x| x - (old - v)),
+            Err(_) => Err("Index overflow"),
+        }
     }
 
     pub fn sum(&self, i: usize) -> Result<T, &str> {
@@ -81,6 +108,20 @@ where
         }
     }
 
+    ///## Iter
+    /// Iterates over the items, reconstructed in linear time by undoing the
+    /// partial sums of the construction (see `from`)
+    pub fn iter(&self) -> impl Iterator<Item = T> + use<T> {
+        let mut v = self.tree.clone();
+        for i in (1..v.len()).rev() {
+            let j = Self::next_sibling(i);
+            if j < v.len() {
+                v[j] = v[j] - v[i];
+            }
+        }
+        v.into_iter().skip(1)
+    }
+
     fn isolate_trailing_one(i: usize) -> usize {
         if i == 0 { 0 } else { 1 << i.trailing_zeros() }
     }
@@ -99,6 +140,42 @@ where
     }
 }
 
+impl<T> FenwickTree<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + Clone + Copy + Default + Ord,
+{
+    ///## Lower Bound
+    /// Returns the first index `i` such that `sum(i) >= prefix`, or `None` if the sum
+    /// of all items is lower than `prefix`.
+    ///
+    /// Requires non negative items, so that prefix sums are non decreasing.
+    ///
+    /// Instead of a binary search over `sum` (O(log^2(n))) we descend the implicit tree
+    /// from the largest power of two: whenever the node covering the next `step` items
+    /// doesn't reach the target we skip those items and subtract their sum. It takes O(log(n)).
+    ///
+    /// With a tree of appearance counts, `lower_bound(k + 1)` is the k-th smallest item.
+    pub fn lower_bound(&self, prefix: T) -> Option<usize> {
+        let n = self.len();
+        let (mut pos, mut rem) = (0, prefix);
+        let mut step = if n == 0 { 0 } else { 1 << n.ilog2() };
+        while step > 0 {
+            if pos + step <= n && self.tree[pos + step] < rem {
+                pos += step;
+                rem = rem - self.tree[pos];
+            }
+            step /= 2;
+        }
+        //pos is the longest prefix (1-indexed) with a sum lower than the target
+        if pos < n { Some(pos) } else { None }
+    }
+}
+
+///
+/// ## Update Array
+///
+/// Range updates and point queries: the tree stores the difference array `d`
+/// (`d[i] = a[i] - a[i - 1]`), so `a[i]` is the prefix sum `d[0..=i]`.
 #[derive(Debug)]
 pub struct UpdateArray<T> {
     ft: FenwickTree<T>,
@@ -133,7 +210,7 @@ where
 
         let _ = self.ft.add(l, v);
         if r + 1 < ln {
-            let _ = self.ft.add(r, -v);
+            let _ = self.ft.add(r + 1, -v);
         }
         Ok(())
     }
@@ -145,6 +222,23 @@ where
     }
 }
 
+///
+/// ## Range Update
+///
+/// Range additions and range sums with two Fenwick trees (dual BIT).
+///
+/// ### Strategy
+/// `ft1` stores the difference array `d`, so `a[i] = d[0] + ... + d[i]` and the prefix sum is
+///
+/// `a[0] + ... + a[i] = sum_{j <= i} d[j] * (i + 1 - j) = (i + 1) * sum(d[j]) - sum(d[j] * j)`
+///
+/// `ft2` stores the correction terms `d[j] * j`, so a prefix sum is two prefix queries and a
+/// range addition is two point updates on each tree.
+///
+/// ### Complexity
+/// `O(n)` space and build time, `O(log(n))` per update or query.
+///
+#[derive(Debug)]
 pub struct RangeUpdate<T> {
     ft1: FenwickTree<T>,
     ft2: FenwickTree<T>,
@@ -155,53 +249,98 @@ where
     T: Neg<Output = T>
         + Sub<Output = T>
         + Add<Output = T>
-        + Mul<usize, Output = T>
+        + Mul<Output = T>
+        + TryFrom<usize>
         + Clone
         + Copy
         + Default,
 {
-    pub fn with_len(n: usize, sum_invariant: T) -> Self {
+    /// Creates `n` items equal to `T::default()`
+    pub fn with_len(n: usize) -> Self {
+        Self {
+            ft1: FenwickTree::with_len(n, T::default()),
+            ft2: FenwickTree::with_len(n, T::default()),
+        }
+    }
+
+    /// Builds both trees from a slice in linear time
+    pub fn from(slice: &[T]) -> Self {
+        let mut d = Vec::with_capacity(slice.len());
+        let mut d_idx = Vec::with_capacity(slice.len());
+        let mut prev = T::default();
+        for (i, &v) in slice.iter().enumerate() {
+            d.push(v - prev);
+            d_idx.push((v - prev) * Self::index(i));
+            prev = v;
+        }
         Self {
-            ft1: FenwickTree::with_len(n, sum_invariant),
-            ft2: FenwickTree::with_len(n, sum_invariant),
+            ft1: FenwickTree::from(&d),
+            ft2: FenwickTree::from(&d_idx),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.ft1.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ft1.is_empty()
+    }
+
+    /// Converts an index to the value type, to scale the correction terms
+    fn index(i: usize) -> T {
+        match T::try_from(i) {
+            Ok(i) => i,
+            Err(_) => panic!("Index not representable"),
         }
     }
 
     pub fn access(&self, i: usize) -> Result<T, &str> {
-        self.ft1.access(i)
+        self.ft1.sum(i)
     }
 
+    ///## Sum
+    /// Prefix sum of the items `[0, i]`
     pub fn sum(&self, i: usize) -> Result<T, &str> {
-        let l = self.ft1.sum(i)?;
-        let r = self.ft2.sum(i)?;
-        Ok(l + r)
+        let d = self.ft1.sum(i)?;
+        let correction = self.ft2.sum(i)?;
+        Ok(d * Self::index(i + 1) - correction)
     }
 
-    fn multiply(v: T, i: usize) -> T {
-        let mut v1 = v;
-        for _ in 1..i {
-            v1 = v1 + v
+    pub fn range_sum(&self, l: usize, r: usize) -> Result<T, &str> {
+        if l > r {
+            return Err("Left index out of range");
+        }
+        let sum = self.sum(r)?;
+        if l == 0 {
+            Ok(sum)
+        } else {
+            Ok(sum - self.sum(l - 1)?)
         }
-        v1
     }
 
+    ///## Range Update
+    /// Adds `v` to every item in `[l, r]`
     pub fn range_update(&mut self, l: usize, r: usize, v: T) -> Result<(), &str> {
         if l > r {
             return Err("Left index out of range");
-        } else if r >= self.ft1.len() {
-            return Err("Right Index out of bounds");
+        } else if r >= self.len() {
+            return Err("Right index out of bounds");
         }
 
         let _ = self.ft1.add(l, v);
+        let _ = self.ft2.add(l, v * Self::index(l));
 
-        //add the error correction term
-        let _ = self.ft2.add(l, -v * (l - 1));
-
-        if (r + 1) < self.ft1.len() {
+        if r + 1 < self.len() {
             let _ = self.ft1.add(r + 1, -v);
-            self.ft2.add(r + 1, RangeUpdate::multiply(v, r))?;
+            let _ = self.ft2.add(r + 1, -v * Self::index(r + 1));
         }
-
         Ok(())
     }
+
+    ///## Add
+    /// Add is just a special case of range_update where left == right
+    pub fn add(&mut self, i: usize, v: T) -> Result<(), &str> {
+        self.range_update(i, i, v)
+    }
 }
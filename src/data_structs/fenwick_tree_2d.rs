@@ -0,0 +1,182 @@
+use std::ops::{Add, Sub};
+
+///
+/// ## 2D Fenwick Tree
+///
+/// Point updates and rectangle sums on a `rows x cols` grid.
+///
+/// The grid is given (and the tree is stored) flattened in row-major order: the cell `(r, c)`
+/// is at `r * cols + c`. The tree is 1-indexed on both dimensions, so it takes
+/// `(rows + 1) * (cols + 1)` slots where the first row and the first column are unused.
+///
+/// Every node `(i, j)` stores the sum of the cells covered by the 1D node `i` on the rows and
+/// the 1D node `j` on the columns (see `FenwickTree`), so updates and prefix queries are
+/// the nested 1D walks.
+///
+/// ### Complexity
+/// `O(rows * cols)` space and build time, `O(log(rows) * log(cols))` per update or query.
+///
+#[derive(Debug)]
+pub struct FenwickTree2D<T> {
+    rows: usize,
+    cols: usize,
+    tree: Vec<T>,
+}
+
+impl<T> FenwickTree2D<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + Clone + Copy + Default,
+{
+    /// Creates a `rows x cols` grid of `T::default()`
+    pub fn with_dims(rows: usize, cols: usize) -> Self {
+        Self {
+            rows,
+            cols,
+            tree: vec![T::default(); (rows + 1) * (cols + 1)],
+        }
+    }
+
+    ///
+    /// Builds the tree from a flattened grid in linear time
+    ///
+    /// As in the 1D case every node pushes its partial sum to the next node covering it,
+    /// first along the rows of the grid and then along the columns.
+    ///
+    /// ## Panics
+    /// If `grid.len() != rows * cols`
+    pub fn from(grid: &[T], rows: usize, cols: usize) -> Self {
+        assert_eq!(rows * cols, grid.len());
+        let mut ft = Self::with_dims(rows, cols);
+        for r in 0..rows {
+            for c in 0..cols {
+                let slot = ft.slot(r + 1, c + 1);
+                ft.tree[slot] = grid[r * cols + c];
+            }
+        }
+        for i in 1..=rows {
+            for j in 1..=cols {
+                let next = j + Self::isolate_trailing_one(j);
+                if next <= cols {
+                    let (from, to) = (ft.slot(i, j), ft.slot(i, next));
+                    ft.tree[to] = ft.tree[to] + ft.tree[from];
+                }
+            }
+        }
+        for i in 1..=rows {
+            let next = i + Self::isolate_trailing_one(i);
+            if next <= rows {
+                for j in 1..=cols {
+                    let (from, to) = (ft.slot(i, j), ft.slot(next, j));
+                    ft.tree[to] = ft.tree[to] + ft.tree[from];
+                }
+            }
+        }
+        ft
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows == 0 || self.cols == 0
+    }
+
+    /// Position of the 1-indexed node `(i, j)` in the flattened tree
+    fn slot(&self, i: usize, j: usize) -> usize {
+        i * (self.cols + 1) + j
+    }
+
+    fn isolate_trailing_one(i: usize) -> usize {
+        if i == 0 { 0 } else { 1 << i.trailing_zeros() }
+    }
+
+    ///## Add
+    /// Adds `delta` to the cell `(r, c)`
+    pub fn add(&mut self, r: usize, c: usize, delta: T) -> Result<(), &str> {
+        self.update(r, c, |x| x + delta)
+    }
+
+    /// Applies `f` to all the nodes covering the cell `(r, c)`
+    fn update(&mut self, r: usize, c: usize, f: impl Fn(T) -> T) -> Result<(), &str> {
+        if r >= self.rows || c >= self.cols {
+            return Err("Index overflow");
+        }
+        let mut i = r + 1;
+        while i <= self.rows {
+            let mut j = c + 1;
+            while j <= self.cols {
+                let slot = self.slot(i, j);
+                self.tree[slot] = f(self.tree[slot]);
+                j += Self::isolate_trailing_one(j);
+            }
+            i += Self::isolate_trailing_one(i);
+        }
+        Ok(())
+    }
+
+    ///## Sum
+    /// Sum of the rectangle from `(0, 0)` to `(r, c)` (inclusive)
+    pub fn sum(&self, r: usize, c: usize) -> Result<T, &str> {
+        if r >= self.rows || c >= self.cols {
+            return Err("Index overflow");
+        }
+        let mut res = T::default();
+        let mut i = r + 1;
+        while i > 0 {
+            let mut j = c + 1;
+            while j > 0 {
+                res = res + self.tree[self.slot(i, j)];
+                j -= Self::isolate_trailing_one(j);
+            }
+            i -= Self::isolate_trailing_one(i);
+        }
+        Ok(res)
+    }
+
+    ///## Rectangle Sum
+    /// Sum of the cells `(r, c)` with `r1 <= r <= r2` and `c1 <= c <= c2`, by inclusion-exclusion
+    /// of four prefix rectangles
+    pub fn rect_sum(&self, r1: usize, c1: usize, r2: usize, c2: usize) -> Result<T, &str> {
+        if r1 > r2 || c1 > c2 {
+            return Err("Range not supported");
+        }
+        //bottom right and top left are added first: no intermediate result goes below zero on
+        //non-negative data, so unsigned types don't underflow
+        let mut res = self.sum(r2, c2)?;
+        if r1 > 0 && c1 > 0 {
+            res = res + self.sum(r1 - 1, c1 - 1)?;
+        }
+        if r1 > 0 {
+            res = res - self.sum(r1 - 1, c2)?;
+        }
+        if c1 > 0 {
+            res = res - self.sum(r2, c1 - 1)?;
+        }
+        Ok(res)
+    }
+
+    pub fn access(&self, r: usize, c: usize) -> Result<T, &str> {
+        self.rect_sum(r, c, r, c)
+    }
+
+    ///## Set
+    /// Replaces the cell `(r, c)` adding the difference with the current value
+    ///
+    /// When the value decreases the difference is subtracted instead, so that it never goes
+    /// below zero on unsigned types
+    pub fn set(&mut self, r: usize, c: usize, v: T) -> Result<(), &str>
+    where
+        T: PartialOrd,
+    {
+        match self.access(r, c) {
+            Ok(old) if v >= old => self.add(r, c, v - old),
+            Ok(old) => self.update(r, c, |x| x - (old - v)),
+            Err(_) => Err("Index overflow"),
+        }
+    }
+}
@@ -0,0 +1,276 @@
+use crate::data_structs::monoid::Monoid;
+use crate::data_structs::treap::{self, Priorities, drop_iteratively};
+
+type Link<V> = treap::Link<Node<V>>;
+
+///
+/// Node of an `ImplicitTreap`: besides its value it stores the aggregate of its subtree in
+/// order (`agg`) and in reverse order (`rev_agg`), so that a reversal only swaps them.
+/// `reversed` means that the children still have to be swapped.
+struct Node<V> {
+    val: V,
+    agg: V,
+    rev_agg: V,
+    size: usize,
+    priority: u64,
+    reversed: bool,
+    left: Link<V>,
+    right: Link<V>,
+}
+
+fn size<V>(t: &Link<V>) -> usize {
+    t.as_ref().map_or(0, |n| n.size)
+}
+
+///
+/// ## Implicit Treap (rope)
+///
+/// Sequence of values of a `Monoid` that supports, in `O(log(n))` expected time:
+/// - `split_at` / `merge` of whole sequences
+/// - `insert_at` / `remove_at` at any position
+/// - `reverse(l, r)` of a range
+/// - `query(l, r)`: aggregate of a range
+///
+/// ### Strategy
+/// A treap (see `OrderStatisticTree`) where the key of a node is its position, which is not
+/// stored but implied by the subtree sizes: the position of a node is the number of nodes
+/// before it in order. So `split(k)` divides a tree in its first `k` values and the others,
+/// and `merge` concatenates two sequences.
+///
+/// Every range operation splits the range out, works on the root of its tree and merges the
+/// pieces back. A reversal is lazy: the root swaps its aggregates and marks its children to
+/// be swapped, which is pushed down when a node is visited. Since values can be combined in a
+/// non commutative way every node also keeps the aggregate of its subtree in reverse order.
+///
+/// Ranges are inclusive and not circular.
+///
+/// ### Complexity
+/// `O(n)` space, `O(log(n))` expected per operation.
+///
+pub struct ImplicitTreap<M: Monoid> {
+    root: Link<M::Value>,
+    priorities: Priorities,
+}
+
+impl<M: Monoid> Default for ImplicitTreap<M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<M: Monoid> ImplicitTreap<M> {
+    pub fn new() -> Self {
+        Self {
+            root: None,
+            priorities: Priorities::default(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        size(&self.root)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    fn leaf(&mut self, val: M::Value) -> Link<M::Value> {
+        Some(Box::new(Node {
+            agg: val.clone(),
+            rev_agg: val.clone(),
+            val,
+            size: 1,
+            priority: self.priorities.next_priority(),
+            reversed: false,
+            left: None,
+            right: None,
+        }))
+    }
+
+    /// Reverses the whole subtree lazily
+    fn toggle(node: &mut Node<M::Value>) {
+        std::mem::swap(&mut node.agg, &mut node.rev_agg);
+        node.reversed = !node.reversed;
+    }
+
+    fn push(node: &mut Node<M::Value>) {
+        if node.reversed {
+            std::mem::swap(&mut node.left, &mut node.right);
+            for child in [&mut node.left, &mut node.right].into_iter().flatten() {
+                Self::toggle(child);
+            }
+            node.reversed = false;
+        }
+    }
+
+    fn pull(node: &mut Node<M::Value>) {
+        let id = M::identity();
+        let (l, r) = (node.left.as_deref(), node.right.as_deref());
+        let (l_agg, l_rev) = l.map_or((&id, &id), |n| (&n.agg, &n.rev_agg));
+        let (r_agg, r_rev) = r.map_or((&id, &id), |n| (&n.agg, &n.rev_agg));
+        node.agg = M::combine(&M::combine(l_agg, &node.val), r_agg);
+        node.rev_agg = M::combine(&M::combine(r_rev, &node.val), l_rev);
+        node.size = 1 + size(&node.left) + size(&node.right);
+    }
+
+    /// Splits `t` in its first `k` values and the others
+    fn split(t: Link<M::Value>, k: usize) -> (Link<M::Value>, Link<M::Value>) {
+        let Some(mut node) = t else {
+            return (None, None);
+        };
+        Self::push(&mut node);
+        if size(&node.left) < k {
+            let k = k - size(&node.left) - 1;
+            let (l, r) = Self::split(node.right.take(), k);
+            node.right = l;
+            Self::pull(&mut node);
+            (Some(node), r)
+        } else {
+            let (l, r) = Self::split(node.left.take(), k);
+            node.left = r;
+            Self::pull(&mut node);
+            (l, Some(node))
+        }
+    }
+
+    /// Concatenates `a` and `b`
+    fn join(a: Link<M::Value>, b: Link<M::Value>) -> Link<M::Value> {
+        match (a, b) {
+            (None, t) | (t, None) => t,
+            (Some(mut a), Some(mut b)) => {
+                if a.priority > b.priority {
+                    Self::push(&mut a);
+                    a.right = Self::join(a.right.take(), Some(b));
+                    Self::pull(&mut a);
+                    Some(a)
+                } else {
+                    Self::push(&mut b);
+                    b.left = Self::join(Some(a), b.left.take());
+                    Self::pull(&mut b);
+                    Some(b)
+                }
+            }
+        }
+    }
+
+    fn check_range(&self, l: usize, r: usize) {
+        if l > r || r >= self.len() {
+            panic!("Range not supported")
+        }
+    }
+
+    ///
+    /// Splits the tree in `[0,l)`, `[l,r]` and `(r,n)`, applies `f` to the root of the middle
+    /// tree and merges them back
+    fn with_range<R>(&mut self, l: usize, r: usize, f: impl FnOnce(&mut Node<M::Value>) -> R) -> R {
+        self.check_range(l, r);
+        let (left, rest) = Self::split(self.root.take(), l);
+        let (mut mid, right) = Self::split(rest, r - l + 1);
+        let res = f(mid.as_mut().unwrap());
+        self.root = Self::join(Self::join(left, mid), right);
+        res
+    }
+
+    /* PUBLIC API */
+    ///
+    /// Splits the sequence in its first `k` values and the others
+    ///
+    /// ## Panics
+    /// If `k > len()`
+    pub fn split_at(mut self, k: usize) -> (Self, Self) {
+        if k > self.len() {
+            panic!("Index out of bounds")
+        }
+        let (l, r) = Self::split(self.root.take(), k);
+        let right = Self {
+            root: r,
+            priorities: self.priorities.fork(),
+        };
+        self.root = l;
+        (self, right)
+    }
+
+    /* PUBLIC API */
+    /// Appends `other` at the end of the sequence
+    pub fn merge(mut self, mut other: Self) -> Self {
+        self.root = Self::join(self.root.take(), other.root.take());
+        self
+    }
+
+    /* PUBLIC API */
+    /// Inserts `val` at position `i`, shifting the following values
+    pub fn insert_at(&mut self, i: usize, val: M::Value) {
+        if i > self.len() {
+            panic!("Index out of bounds")
+        }
+        let leaf = self.leaf(val);
+        let (l, r) = Self::split(self.root.take(), i);
+        self.root = Self::join(Self::join(l, leaf), r);
+    }
+
+    /* PUBLIC API */
+    /// Appends `val` at the end of the sequence
+    pub fn push_back(&mut self, val: M::Value) {
+        self.insert_at(self.len(), val)
+    }
+
+    /* PUBLIC API */
+    /// Removes the value at position `i`, shifting the following values
+    pub fn remove_at(&mut self, i: usize) -> M::Value {
+        self.check_range(i, i);
+        let (l, rest) = Self::split(self.root.take(), i);
+        let (mid, r) = Self::split(rest, 1);
+        self.root = Self::join(l, r);
+        mid.unwrap().val
+    }
+
+    /* PUBLIC API */
+    pub fn get(&mut self, i: usize) -> M::Value {
+        self.query(i, i)
+    }
+
+    /* PUBLIC API */
+    /// Aggregate of the values in `[l,r]`
+    pub fn query(&mut self, l: usize, r: usize) -> M::Value {
+        self.with_range(l, r, |node| node.agg.clone())
+    }
+
+    /* PUBLIC API */
+    /// Reverses the values in `[l,r]`
+    pub fn reverse(&mut self, l: usize, r: usize) {
+        self.with_range(l, r, Self::toggle)
+    }
+
+    fn collect(t: &mut Link<M::Value>, res: &mut Vec<M::Value>) {
+        if let Some(node) = t {
+            Self::push(node);
+            Self::collect(&mut node.left, res);
+            res.push(node.val.clone());
+            Self::collect(&mut node.right, res);
+        }
+    }
+
+    /* PUBLIC API */
+    /// Values of the sequence, in order
+    pub fn to_vec(&mut self) -> Vec<M::Value> {
+        let mut res = Vec::with_capacity(self.len());
+        Self::collect(&mut self.root, &mut res);
+        res
+    }
+}
+
+impl<M: Monoid> Drop for ImplicitTreap<M> {
+    fn drop(&mut self) {
+        drop_iteratively(self.root.take(), |n| [n.left.take(), n.right.take()]);
+    }
+}
+
+impl<M: Monoid> FromIterator<M::Value> for ImplicitTreap<M> {
+    fn from_iter<I: IntoIterator<Item = M::Value>>(iter: I) -> Self {
+        let mut t = Self::new();
+        for v in iter {
+            t.push_back(v);
+        }
+        t
+    }
+}
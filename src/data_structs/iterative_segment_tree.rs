@@ -0,0 +1,116 @@
+use crate::data_structs::monoid::Monoid;
+
+///
+/// ## Iterative (bottom-up) Segment Tree
+///
+/// Non recursive segment tree generic over a `Monoid`, with the same API of `SegmentTree`
+/// (point updates and range queries, circular when `l > r`), so it can replace it on
+/// workloads that don't need lazy propagation.
+///
+/// ### Layout
+/// The tree takes exactly `2n` slots: leaves are stored in `tree[n..2n]` and the parent of
+/// node `i` is `i / 2` (slot `0` is unused). For `n` not a power of two some internal nodes
+/// cover non contiguous leaves, but they are never used by a query.
+///
+/// An update rewrites the leaf and walks up to the root. A query starts from the two leaves
+/// at the ends of the range and climbs until they meet: every time a bound is a right child
+/// (left bound) or a left child (right bound) its node is entirely in the range, so it is
+/// folded in and the bound moves past it. Left and right results are accumulated
+/// separately, so non commutative monoids are combined in order.
+///
+/// ### Complexity
+/// `2n` space, `O(n)` build, `O(log(n))` monoid operations per update or query with no
+/// recursion and a cache friendly access pattern.
+///
+pub struct IterativeSegmentTree<M: Monoid> {
+    n: usize,
+    tree: Vec<M::Value>,
+}
+
+impl<M: Monoid> IterativeSegmentTree<M> {
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    ///
+    /// Builds the tree from a slice of values
+    ///
+    /// An empty slice produces an empty tree
+    pub fn build(a: &[M::Value]) -> Self {
+        a.iter().cloned().collect()
+    }
+
+    /// Internal folding of the non circular range `[l,r]`
+    fn fold(&self, l: usize, r: usize) -> M::Value {
+        let (mut res_left, mut res_right) = (M::identity(), M::identity());
+        let (mut l, mut r) = (l + self.n, r + self.n + 1); //half open range of slots
+        while l < r {
+            if l & 1 == 1 {
+                res_left = M::combine(&res_left, &self.tree[l]);
+                l += 1;
+            }
+            if r & 1 == 1 {
+                r -= 1;
+                res_right = M::combine(&self.tree[r], &res_right);
+            }
+            l /= 2;
+            r /= 2;
+        }
+        M::combine(&res_left, &res_right)
+    }
+
+    /* PUBLIC API */
+    pub fn set(&mut self, i: usize, val: M::Value) {
+        if i >= self.n {
+            panic!("Index out of bounds")
+        }
+        let mut i = i + self.n;
+        self.tree[i] = val;
+        while i > 1 {
+            i /= 2;
+            self.tree[i] = M::combine(&self.tree[2 * i], &self.tree[2 * i + 1]);
+        }
+    }
+
+    /* PUBLIC API */
+    pub fn get(&self, i: usize) -> M::Value {
+        if i >= self.n {
+            panic!("Index out of bounds")
+        }
+        self.tree[i + self.n].clone()
+    }
+
+    /* PUBLIC API */
+    pub fn query(&self, qleft: usize, qright: usize) -> M::Value {
+        if qleft >= (self.n) || qright >= (self.n) {
+            panic!("Range not supported")
+        }
+        if qleft <= qright {
+            self.fold(qleft, qright)
+        } else {
+            M::combine(&self.fold(qleft, self.n - 1), &self.fold(0, qright))
+        }
+    }
+}
+
+impl<M: Monoid> FromIterator<M::Value> for IterativeSegmentTree<M> {
+    ///
+    /// Builds the tree from the values yielded by an iterator: leaves are filled in order
+    /// and internal nodes are computed from the last to the first
+    ///
+    /// An empty iterator produces an empty tree
+    fn from_iter<I: IntoIterator<Item = M::Value>>(iter: I) -> Self {
+        let leaves: Vec<M::Value> = iter.into_iter().collect();
+        let n = leaves.len();
+        let mut tree = vec![M::identity(); n];
+        tree.extend(leaves);
+        for i in (1..n).rev() {
+            tree[i] = M::combine(&tree[2 * i], &tree[2 * i + 1]);
+        }
+        Self { n, tree }
+    }
+}
@@ -0,0 +1,203 @@
+use crate::data_structs::action::Action;
+use crate::data_structs::monoid::Monoid;
+use std::marker::PhantomData;
+
+///
+/// Segment tree with lazy propagation, generic over a `Monoid` (the aggregate) and an
+/// `Action` (the range update)
+///
+/// Every node stores the aggregate of its segment and a pending tag. A range update on a
+/// totally overlapped node is applied to its aggregate and composed into its tag, children
+/// receive the tag only when a later operation needs to visit them (`push`).
+///
+/// So the same structure handles range add, range assign, range chmin or affine updates
+/// (see `data_structs::action`) over min, max, sum or custom aggregates.
+///
+/// ## Circular ranges
+/// As in `SegmentTree`, both ends of a range are inclusive and a range `[l,r]` with `l > r`
+/// covers `[l,n-1] u [0,r]`.
+///
+/// ## Complexity
+/// Lazy propagation doubles the memory required: the tree and the tags take `O(n)` space each.
+/// Every update and query costs `O(log(n))` monoid and action operations.
+///
+pub struct LazySegmentTree<M: Monoid, A: Action<M>> {
+    n: usize,
+    tree: Vec<M::Value>,
+    lazy: Vec<A::Value>,
+    action: PhantomData<A>,
+}
+
+impl<M: Monoid, A: Action<M>> LazySegmentTree<M, A> {
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    fn construct(a: &[M::Value], seg: &mut [M::Value], idx: usize, left: usize, right: usize) {
+        if left == right {
+            seg[idx] = a[left].clone();
+            return;
+        }
+        let mid = (left + right) / 2;
+        Self::construct(a, seg, idx * 2 + 1, left, mid);
+        Self::construct(a, seg, idx * 2 + 2, mid + 1, right);
+        seg[idx] = M::combine(&seg[idx * 2 + 1], &seg[idx * 2 + 2]);
+    }
+
+    ///
+    /// Builds the tree from a slice of values, with no pending updates
+    ///
+    /// An empty slice produces an empty tree
+    pub fn build(a: &[M::Value]) -> Self {
+        let n = a.len();
+        let ln = (n.next_power_of_two() * 2) - 1;
+        let mut tree = vec![M::identity(); ln];
+        let lazy = vec![A::identity(); ln];
+        if n > 0 {
+            Self::construct(a, &mut tree, 0, 0, n - 1);
+        }
+        Self {
+            n,
+            tree,
+            lazy,
+            action: PhantomData,
+        }
+    }
+
+    /// Applies a tag to the node `idx`, covering a segment of `len` elements
+    fn apply_node(&mut self, idx: usize, f: &A::Value, len: usize) {
+        self.tree[idx] = A::apply(f, &self.tree[idx], len);
+        self.lazy[idx] = A::compose(f, &self.lazy[idx]);
+    }
+
+    /// Push the pending tag of `idx` down to its children, then reset it.
+    fn push(&mut self, idx: usize, left: usize, right: usize) {
+        let f = std::mem::replace(&mut self.lazy[idx], A::identity());
+        let mid = (left + right) / 2;
+        let base = idx * 2;
+        self.apply_node(base + 1, &f, mid - left + 1);
+        self.apply_node(base + 2, &f, right - mid);
+    }
+
+    ///
+    /// Internal range update that uses lazy propagation to
+    /// perform subtree update on demand
+    ///
+    fn apply_rec(
+        &mut self,
+        idx: usize,
+        left: usize,
+        right: usize,
+        qleft: usize,
+        qright: usize,
+        f: &A::Value,
+    ) {
+        if qleft > right || qright < left {
+            return; //no overlap
+        }
+        if qleft <= left && qright >= right {
+            //total overlap
+            self.apply_node(idx, f, right - left + 1);
+            return;
+        }
+
+        //partial overlap: assure the children are updated
+        self.push(idx, left, right);
+        let mid = (left + right) / 2;
+        let base = idx * 2;
+        self.apply_rec(base + 1, left, mid, qleft, qright, f);
+        self.apply_rec(base + 2, mid + 1, right, qleft, qright, f);
+
+        //update the current node based on the new value of children
+        self.tree[idx] = M::combine(&self.tree[base + 1], &self.tree[base + 2]);
+    }
+
+    ///
+    /// Internal point update: pushes the tags on the path to the leaf, so that the
+    /// new value isn't affected by updates issued before it
+    fn set_rec(&mut self, idx: usize, left: usize, right: usize, pos: usize, val: M::Value) {
+        if left == right {
+            self.tree[idx] = val;
+            return;
+        }
+        self.push(idx, left, right);
+        let mid = (left + right) / 2;
+        let base = idx * 2;
+        if pos <= mid {
+            self.set_rec(base + 1, left, mid, pos, val);
+        } else {
+            self.set_rec(base + 2, mid + 1, right, pos, val);
+        }
+        self.tree[idx] = M::combine(&self.tree[base + 1], &self.tree[base + 2]);
+    }
+
+    ///
+    /// Internal range query that uses lazy propagation to ensure consistency
+    /// of updates to subtrees on demand
+    ///
+    fn query_rec(
+        &mut self,
+        idx: usize,
+        left: usize,
+        right: usize,
+        qleft: usize,
+        qright: usize,
+    ) -> M::Value {
+        if qleft > right || qright < left {
+            return M::identity(); //no overlap
+        } else if qleft <= left && qright >= right {
+            return self.tree[idx].clone(); //total overlap
+        }
+
+        //partial overlap
+        self.push(idx, left, right); //ensure children are updated
+        let mid = (right + left) / 2;
+        let v_left = self.query_rec(idx * 2 + 1, left, mid, qleft, qright);
+        let v_right = self.query_rec(idx * 2 + 2, mid + 1, right, qleft, qright);
+        M::combine(&v_left, &v_right)
+    }
+
+    /* PUBLIC API */
+    pub fn apply(&mut self, qleft: usize, qright: usize, f: A::Value) {
+        if qleft >= (self.n) || qright >= (self.n) {
+            panic!("Range not supported")
+        }
+        if qleft <= qright {
+            self.apply_rec(0, 0, self.n - 1, qleft, qright, &f)
+        } else {
+            self.apply_rec(0, 0, self.n - 1, qleft, self.n - 1, &f);
+            self.apply_rec(0, 0, self.n - 1, 0, qright, &f);
+        }
+    }
+
+    /* PUBLIC API */
+    pub fn set(&mut self, i: usize, val: M::Value) {
+        if i >= self.n {
+            panic!("Index out of bounds")
+        }
+        self.set_rec(0, 0, self.n - 1, i, val)
+    }
+
+    /* PUBLIC API */
+    pub fn get(&mut self, i: usize) -> M::Value {
+        self.query(i, i)
+    }
+
+    /* PUBLIC API */
+    pub fn query(&mut self, qleft: usize, qright: usize) -> M::Value {
+        if qleft >= (self.n) || qright >= (self.n) {
+            panic!("Range not supported")
+        }
+        if qleft <= qright {
+            self.query_rec(0, 0, self.n - 1, qleft, qright)
+        } else {
+            let v_left = self.query_rec(0, 0, self.n - 1, qleft, self.n - 1);
+            let v_right = self.query_rec(0, 0, self.n - 1, 0, qright);
+            M::combine(&v_left, &v_right)
+        }
+    }
+}
@@ -0,0 +1,140 @@
+///
+/// ## Merge Sort Tree
+///
+/// Segment tree where every node stores the sorted values of its range (the intermediate
+/// steps of a merge sort). It answers online, on a static sequence, how many values of
+/// `a[l..=r]` fall in a value range.
+///
+/// ### Fractional cascading
+/// A query splits `[l,r]` in `O(log(n))` nodes, and a binary search in each of them would
+/// cost `O(log^2(n))`. Instead every node also stores, for each prefix of its sorted values,
+/// how many of them come from the left child: if `p` values of a node are below a bound,
+/// `left_count[p]` values of the left child and `p - left_count[p]` of the right child are
+/// below it too. So we binary search only in the root and carry the positions down.
+///
+/// ### Complexity
+/// `O(nlog(n))` space and build time, `O(log(n))` per query.
+///
+pub struct MergeSortTree<T> {
+    n: usize,
+    sorted: Vec<Vec<T>>,
+    left_count: Vec<Vec<usize>>,
+}
+
+impl<T: Ord + Copy> MergeSortTree<T> {
+    fn construct(&mut self, a: &[T], idx: usize, left: usize, right: usize) {
+        if left == right {
+            self.sorted[idx] = vec![a[left]];
+            return;
+        }
+        let mid = (left + right) / 2;
+        let (l, r) = (idx * 2 + 1, idx * 2 + 2);
+        self.construct(a, l, left, mid);
+        self.construct(a, r, mid + 1, right);
+
+        //merge the children, counting the values taken from the left one
+        let (ls, rs) = (&self.sorted[l], &self.sorted[r]);
+        let mut merged = Vec::with_capacity(ls.len() + rs.len());
+        let mut counts = Vec::with_capacity(ls.len() + rs.len() + 1);
+        let (mut i, mut j) = (0, 0);
+        counts.push(0);
+        while i < ls.len() || j < rs.len() {
+            if j == rs.len() || (i < ls.len() && ls[i] <= rs[j]) {
+                merged.push(ls[i]);
+                i += 1;
+            } else {
+                merged.push(rs[j]);
+                j += 1;
+            }
+            counts.push(i);
+        }
+        self.sorted[idx] = merged;
+        self.left_count[idx] = counts;
+    }
+
+    ///
+    /// Builds the tree from a slice
+    ///
+    /// An empty slice produces an empty tree
+    pub fn new(a: &[T]) -> Self {
+        let n = a.len();
+        let ln = (n.next_power_of_two() * 2) - 1;
+        let mut t = Self {
+            n,
+            sorted: vec![vec![]; ln],
+            left_count: vec![vec![]; ln],
+        };
+        if n > 0 {
+            t.construct(a, 0, 0, n - 1);
+        }
+        t
+    }
+
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    ///
+    /// Internal query: `lo` and `hi` are the positions of the bounds in the sorted values of
+    /// `idx`, so the node holds `hi - lo` values in range
+    fn count_rec(
+        &self,
+        idx: usize,
+        left: usize,
+        right: usize,
+        qleft: usize,
+        qright: usize,
+        (lo, hi): (usize, usize),
+    ) -> usize {
+        if qleft > right || qright < left || lo == hi {
+            0 //no overlap or no value in range
+        } else if qleft <= left && qright >= right {
+            hi - lo //total overlap
+        } else {
+            //partial overlap
+            let mid = (left + right) / 2;
+            let counts = &self.left_count[idx];
+            let (l_lo, l_hi) = (counts[lo], counts[hi]);
+            self.count_rec(idx * 2 + 1, left, mid, qleft, qright, (l_lo, l_hi))
+                + self.count_rec(
+                    idx * 2 + 2,
+                    mid + 1,
+                    right,
+                    qleft,
+                    qright,
+                    (lo - l_lo, hi - l_hi),
+                )
+        }
+    }
+
+    /* PUBLIC API */
+    /// How many values of `a[l..=r]` fall in `[lo, hi]`
+    pub fn count_range(&self, l: usize, r: usize, lo: T, hi: T) -> usize {
+        if l > r || r >= self.n {
+            panic!("Range not supported")
+        }
+        if lo > hi {
+            return 0;
+        }
+        let root = &self.sorted[0];
+        let (p_lo, p_hi) = (
+            root.partition_point(|v| *v < lo),
+            root.partition_point(|v| *v <= hi),
+        );
+        self.count_rec(0, 0, self.n - 1, l, r, (p_lo, p_hi))
+    }
+
+    /* PUBLIC API */
+    /// How many values of `a[l..=r]` are `< x`
+    pub fn count_less(&self, l: usize, r: usize, x: T) -> usize {
+        if l > r || r >= self.n {
+            panic!("Range not supported")
+        }
+        let p = self.sorted[0].partition_point(|v| *v < x);
+        self.count_rec(0, 0, self.n - 1, l, r, (0, p))
+    }
+}
@@ -0,0 +1,256 @@
+///
+/// State of a Mo's algorithm run: it describes the current window `[left,right]` of the
+/// sequence and it is updated one index at a time
+///
+pub trait MoState {
+    type Answer;
+
+    /// Index `i` enters the window (right before its left end or right after its right end)
+    fn add(&mut self, i: usize);
+
+    /// Index `i` leaves the window (it is one of its ends)
+    fn remove(&mut self, i: usize);
+
+    /// Answer for the current window
+    fn answer(&self) -> Self::Answer;
+}
+
+///
+/// State of a Mo's algorithm run with point updates: besides the window, it owns the sequence
+/// and the list of updates (numbered in time order) and it can move forward or backward in time
+///
+pub trait MoUpdateState: MoState {
+    /// Index of the sequence written by update `t`
+    fn position(&self, t: usize) -> usize;
+
+    ///
+    /// Applies update `t` if it isn't applied, undoes it otherwise. Updates are always
+    /// toggled in time order (forward) or in reverse time order (backward).
+    ///
+    /// Assignments can be implemented by swapping the value in the sequence with the one
+    /// stored in the update, so that the same call both applies and undoes it.
+    fn toggle(&mut self, t: usize);
+}
+
+///
+/// Order in which the queries are processed
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum QueryOrder {
+    /// Queries are grouped in `sqrt(n)` blocks by their left end and sorted by right end
+    /// (alternating the direction on odd blocks)
+    #[default]
+    Block,
+    /// Queries are sorted along a Hilbert curve over the `(left, right)` plane
+    Hilbert,
+}
+
+///
+/// Position of `(x, y)` along the Hilbert curve filling a `side x side` grid (`side` is a
+/// power of two): at every level we find the quadrant of the point, add the cells of the
+/// quadrants visited before it and rotate the point into the orientation of the quadrant.
+fn hilbert_index(side: usize, x: usize, y: usize) -> u64 {
+    let (mut x, mut y) = (x as u64, y as u64);
+    let mut d = 0;
+    let mut s = side as u64 / 2;
+    while s > 0 {
+        let rx = ((x & s) > 0) as u64;
+        let ry = ((y & s) > 0) as u64;
+        d += s * s * ((3 * rx) ^ ry);
+        if ry == 0 {
+            if rx == 1 {
+                x = s - 1 - (x & (s - 1));
+                y = s - 1 - (y & (s - 1));
+            }
+            std::mem::swap(&mut x, &mut y);
+        }
+        s /= 2;
+    }
+    d
+}
+
+///
+/// Processing order of the queries (as indices in `queries`)
+fn sorted_queries(n: usize, queries: &[(usize, usize)], order: QueryOrder) -> Vec<usize> {
+    let mut idx: Vec<usize> = (0..queries.len()).collect();
+    match order {
+        QueryOrder::Block => {
+            let block_size = n.isqrt().max(1);
+            idx.sort_by_key(|&i| {
+                let (l, r) = queries[i];
+                let block = l / block_size;
+                //odd blocks sweep the right end backwards, saving the return trip
+                (block, if block.is_multiple_of(2) { r } else { n - r })
+            });
+        }
+        QueryOrder::Hilbert => {
+            let side = n.next_power_of_two();
+            idx.sort_by_cached_key(|&i| hilbert_index(side, queries[i].0, queries[i].1));
+        }
+    }
+    idx
+}
+
+///
+/// ## Mo's algorithm
+///
+/// Answers offline the inclusive range queries `queries` on a sequence of length `n`, given a
+/// `state` that can move the ends of its window one index at a time.
+///
+/// ### Strategy
+/// Queries are reordered so that consecutive windows overlap as much as possible, then the
+/// window is moved from one query to the next with `add` and `remove`. Answers are returned
+/// in the original order of the queries.
+///
+/// The window starts empty at the left end of the first processed query, and it is always
+/// extended before being shrunk, so `remove` is only called on indices inside the window.
+///
+/// ### Complexity
+/// With `QueryOrder::Block` the right end moves `O(n)` times per block and the left end
+/// `O(sqrt(n))` times per query, that is `O((n + q) sqrt(n))` state updates.
+/// `QueryOrder::Hilbert` gives `O(n sqrt(q))` updates, better when `q` is much smaller than `n`.
+///
+/// ## Panics
+/// If a query `(l, r)` has `l > r` or `r >= n`
+///
+pub fn solve_with_order<S: MoState>(
+    n: usize,
+    queries: &[(usize, usize)],
+    state: &mut S,
+    order: QueryOrder,
+) -> Vec<S::Answer> {
+    if queries.iter().any(|&(l, r)| l > r || r >= n) {
+        panic!("Range not supported")
+    }
+
+    let sorted = sorted_queries(n, queries, order);
+    let mut answers: Vec<Option<S::Answer>> = queries.iter().map(|_| None).collect();
+    //half open window [left, right)
+    let (mut left, mut right) = match sorted.first() {
+        Some(&i) => (queries[i].0, queries[i].0),
+        None => return vec![],
+    };
+
+    for i in sorted {
+        let (ql, qr) = queries[i];
+        while left > ql {
+            left -= 1;
+            state.add(left);
+        }
+        while right <= qr {
+            state.add(right);
+            right += 1;
+        }
+        while left < ql {
+            state.remove(left);
+            left += 1;
+        }
+        while right > qr + 1 {
+            right -= 1;
+            state.remove(right);
+        }
+        answers[i] = Some(state.answer());
+    }
+
+    answers.into_iter().map(Option::unwrap).collect()
+}
+
+///
+/// Mo's algorithm with the default `QueryOrder::Block`, see `solve_with_order`
+pub fn solve<S: MoState>(n: usize, queries: &[(usize, usize)], state: &mut S) -> Vec<S::Answer> {
+    solve_with_order(n, queries, state, QueryOrder::Block)
+}
+
+///
+/// ## Mo's algorithm with updates
+///
+/// Answers offline the inclusive range queries `(l, r, t)` on a sequence of length `n` that
+/// receives `updates` point updates: a query is answered on the sequence where only the
+/// first `t` updates are applied.
+///
+/// ### Strategy
+/// Time is a third dimension of the window: besides moving its ends, the window moves
+/// forward (or backward) in time by toggling updates. When the updated index is inside the
+/// window it is removed before the update and added back after it, so `state` only needs
+/// to handle `add` and `remove` of the current values.
+///
+/// Queries are grouped in blocks of `n^(2/3)` by left end, then in blocks by right end,
+/// and sorted by time inside a block.
+///
+/// ### Complexity
+/// The ends move `O(n^(2/3))` per query and time moves `O(u)` per pair of blocks, that is
+/// `O(q n^(2/3) + u n^(2/3))` state updates with `O(n^(1/3))` blocks per end.
+///
+/// ## Panics
+/// If a query `(l, r, t)` has `l > r`, `r >= n` or `t > updates`
+///
+pub fn solve_with_updates<S: MoUpdateState>(
+    n: usize,
+    updates: usize,
+    queries: &[(usize, usize, usize)],
+    state: &mut S,
+) -> Vec<S::Answer> {
+    if queries
+        .iter()
+        .any(|&(l, r, t)| l > r || r >= n || t > updates)
+    {
+        panic!("Range not supported")
+    }
+
+    let block_size = ((n as f64).powf(2.0 / 3.0) as usize).max(1);
+    let mut sorted: Vec<usize> = (0..queries.len()).collect();
+    sorted.sort_by_key(|&i| {
+        let (l, r, t) = queries[i];
+        (l / block_size, r / block_size, t)
+    });
+
+    let mut answers: Vec<Option<S::Answer>> = queries.iter().map(|_| None).collect();
+    //half open window [left, right), with the first `time` updates applied
+    let (mut left, mut right, mut time) = match sorted.first() {
+        Some(&i) => (queries[i].0, queries[i].0, 0),
+        None => return vec![],
+    };
+
+    //toggles update `t`, moving its index out and back into the window if needed
+    let toggle = |state: &mut S, t: usize, left: usize, right: usize| {
+        let pos = state.position(t);
+        let inside = left <= pos && pos < right;
+        if inside {
+            state.remove(pos);
+        }
+        state.toggle(t);
+        if inside {
+            state.add(pos);
+        }
+    };
+
+    for i in sorted {
+        let (ql, qr, qt) = queries[i];
+        while left > ql {
+            left -= 1;
+            state.add(left);
+        }
+        while right <= qr {
+            state.add(right);
+            right += 1;
+        }
+        while left < ql {
+            state.remove(left);
+            left += 1;
+        }
+        while right > qr + 1 {
+            right -= 1;
+            state.remove(right);
+        }
+        while time < qt {
+            toggle(state, time, left, right);
+            time += 1;
+        }
+        while time > qt {
+            time -= 1;
+            toggle(state, time, left, right);
+        }
+        answers[i] = Some(state.answer());
+    }
+
+    answers.into_iter().map(Option::unwrap).collect()
+}
@@ -1,3 +1,24 @@
+pub mod action;
+pub mod binary_tree;
+pub mod dsu;
+pub mod dynamic_segment_tree;
 pub mod fenwick_tree;
+pub mod fenwick_tree_2d;
+pub mod implicit_treap;
+pub mod iterative_segment_tree;
+pub mod lazy_segment_tree;
+pub mod merge_sort_tree;
+pub mod mo;
+pub mod monoid;
+pub mod monotonic;
+pub mod order_statistic_tree;
+pub mod persistent_segment_tree;
+pub mod prefix_sum_2d;
 pub mod segment_tree;
+pub mod segment_tree_beats;
+pub mod sliding_window;
+pub mod sparse_table;
+pub mod sqrt_decomposition;
+mod treap;
 pub mod tree;
+pub mod wavelet_tree;
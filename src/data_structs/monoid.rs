@@ -0,0 +1,128 @@
+use std::marker::PhantomData;
+use std::ops::{Add, BitXor, Rem};
+
+///
+/// A monoid is a set equipped with an associative binary operation and an identity
+/// element: `combine(a, combine(b, c)) == combine(combine(a, b), c)` and
+/// `combine(identity(), a) == a == combine(a, identity())`.
+///
+/// Range structures (segment trees, sliding windows, ...) are generic over this trait
+/// so that the same structure can aggregate with `min`, `max`, `+`, `gcd`, `^` or any
+/// custom operation. The operation is not required to be commutative: structures always
+/// combine values in left-to-right order.
+///
+pub trait Monoid {
+    type Value: Clone;
+
+    /// The neutral element of the operation
+    fn identity() -> Self::Value;
+
+    /// The associative operation. `a` covers the elements on the left of `b`
+    fn combine(a: &Self::Value, b: &Self::Value) -> Self::Value;
+}
+
+///
+/// Integer types with a smallest and a largest representable value.
+///
+/// Used as identities for the `Min` and `Max` monoids.
+///
+pub trait Bounded {
+    const MIN: Self;
+    const MAX: Self;
+}
+
+macro_rules! impl_bounded {
+    ($($t:ty),*) => {
+        $(impl Bounded for $t {
+            const MIN: Self = <$t>::MIN;
+            const MAX: Self = <$t>::MAX;
+        })*
+    };
+}
+
+impl_bounded!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+
+/// Range minimum, with `T::MAX` as identity
+pub struct Min<T>(PhantomData<T>);
+
+impl<T: Ord + Copy + Bounded> Monoid for Min<T> {
+    type Value = T;
+
+    fn identity() -> T {
+        T::MAX
+    }
+
+    fn combine(a: &T, b: &T) -> T {
+        Ord::min(*a, *b)
+    }
+}
+
+/// Range maximum, with `T::MIN` as identity
+pub struct Max<T>(PhantomData<T>);
+
+impl<T: Ord + Copy + Bounded> Monoid for Max<T> {
+    type Value = T;
+
+    fn identity() -> T {
+        T::MIN
+    }
+
+    fn combine(a: &T, b: &T) -> T {
+        Ord::max(*a, *b)
+    }
+}
+
+/// Range sum, with `T::default()` (zero) as identity
+pub struct Sum<T>(PhantomData<T>);
+
+impl<T: Add<Output = T> + Copy + Default> Monoid for Sum<T> {
+    type Value = T;
+
+    fn identity() -> T {
+        T::default()
+    }
+
+    fn combine(a: &T, b: &T) -> T {
+        *a + *b
+    }
+}
+
+///
+/// Range greatest common divisor, with `0` as identity (`gcd(0, x) = x`)
+///
+/// Meant for non negative values: the sign of the result on negative inputs follows
+/// the sign of the remainder operation.
+pub struct Gcd<T>(PhantomData<T>);
+
+impl<T: Rem<Output = T> + PartialEq + Copy + Default> Monoid for Gcd<T> {
+    type Value = T;
+
+    fn identity() -> T {
+        T::default()
+    }
+
+    fn combine(a: &T, b: &T) -> T {
+        let (mut a, mut b) = (*a, *b);
+        while b != T::default() {
+            (a, b) = (b, a % b);
+        }
+        a
+    }
+}
+
+/// Range bitwise xor, with `0` as identity
+pub struct Xor<T>(PhantomData<T>);
+
+impl<T: BitXor<Output = T> + Copy + Default> Monoid for Xor<T> {
+    type Value = T;
+
+    fn identity() -> T {
+        T::default()
+    }
+
+    fn combine(a: &T, b: &T) -> T {
+        *a ^ *b
+    }
+}
@@ -0,0 +1,284 @@
+use std::collections::VecDeque;
+
+/// Comparator of the `max` / `min` constructors
+type Keeps<T> = fn(&T, &T) -> bool;
+
+///
+/// ## Monotonic Stack
+///
+/// Stack whose items are kept monotone by a comparator `keeps(old, new)`: when `new` is
+/// pushed, every item on top for which `keeps` is false is popped first. Every item is
+/// numbered by the order of its push.
+///
+/// With `keeps = |a, b| a > b` the stack is strictly decreasing, so after a push the item
+/// below the new one is the previous strictly greater value, and an item is popped by the
+/// first later value that is greater or equal.
+///
+/// ### Complexity
+/// `O(n)` space, `O(1)` amortized per push (every item is popped at most once).
+///
+pub struct MonotonicStack<T, Cmp> {
+    stack: Vec<(usize, T)>,
+    pushed: usize,
+    keeps: Cmp,
+}
+
+impl<T: Ord> MonotonicStack<T, Keeps<T>> {
+    /// Strictly decreasing stack (the bottom is the max)
+    pub fn max() -> Self {
+        Self::new(|a, b| a > b)
+    }
+
+    /// Strictly increasing stack (the bottom is the min)
+    pub fn min() -> Self {
+        Self::new(|a, b| a < b)
+    }
+}
+
+impl<T, Cmp: Fn(&T, &T) -> bool> MonotonicStack<T, Cmp> {
+    pub fn new(keeps: Cmp) -> Self {
+        Self {
+            stack: vec![],
+            pushed: 0,
+            keeps,
+        }
+    }
+
+    /// Number of items still on the stack
+    pub fn len(&self) -> usize {
+        self.stack.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.stack.is_empty()
+    }
+
+    /* PUBLIC API */
+    /// The top of the stack with its push number
+    pub fn top(&self) -> Option<(usize, &T)> {
+        self.stack.last().map(|(i, v)| (*i, v))
+    }
+
+    /* PUBLIC API */
+    ///
+    /// Pops the top if pushing `v` would evict it
+    ///
+    /// ## Returns
+    /// The evicted item with its push number, `None` if the top is kept (or the stack is empty)
+    pub fn pop_dominated(&mut self, v: &T) -> Option<(usize, T)> {
+        match self.stack.last() {
+            Some((_, top)) if !(self.keeps)(top, v) => self.stack.pop(),
+            _ => None,
+        }
+    }
+
+    /* PUBLIC API */
+    ///
+    /// Evicts the items not kept by `v` and pushes it
+    ///
+    /// ## Returns
+    /// The push number of the item below `v`, i.e. the nearest previous item kept by `v`
+    pub fn push(&mut self, v: T) -> Option<usize> {
+        while self.pop_dominated(&v).is_some() {}
+        let below = self.top().map(|(i, _)| i);
+        self.stack.push((self.pushed, v));
+        self.pushed += 1;
+        below
+    }
+}
+
+///
+/// For every item of `a`, the index of the nearest previous item `p` with `keeps(p, item)`
+pub fn previous_by<T: Clone>(a: &[T], keeps: impl Fn(&T, &T) -> bool) -> Vec<Option<usize>> {
+    let mut stack = MonotonicStack::new(keeps);
+    a.iter().map(|v| stack.push(v.clone())).collect()
+}
+
+///
+/// For every item of `a`, the index of the nearest next item `n` with `keeps(n, item)`
+pub fn next_by<T: Clone>(a: &[T], keeps: impl Fn(&T, &T) -> bool) -> Vec<Option<usize>> {
+    let n = a.len();
+    let mut stack = MonotonicStack::new(keeps);
+    let mut res: Vec<Option<usize>> = a
+        .iter()
+        .rev()
+        .map(|v| stack.push(v.clone()).map(|j| n - 1 - j))
+        .collect();
+    res.reverse();
+    res
+}
+
+/// For every item, the index of the nearest previous strictly greater item
+pub fn previous_greater<T: Ord + Clone>(a: &[T]) -> Vec<Option<usize>> {
+    previous_by(a, |p, v| p > v)
+}
+
+/// For every item, the index of the nearest previous strictly smaller item
+pub fn previous_smaller<T: Ord + Clone>(a: &[T]) -> Vec<Option<usize>> {
+    previous_by(a, |p, v| p < v)
+}
+
+/// For every item, the index of the nearest next strictly greater item
+pub fn next_greater<T: Ord + Clone>(a: &[T]) -> Vec<Option<usize>> {
+    next_by(a, |n, v| n > v)
+}
+
+/// For every item, the index of the nearest next strictly smaller item
+pub fn next_smaller<T: Ord + Clone>(a: &[T]) -> Vec<Option<usize>> {
+    next_by(a, |n, v| n < v)
+}
+
+///
+/// ## Monotonic Queue
+///
+/// FIFO queue that answers in `O(1)` which of its items is the best according to a
+/// comparator `keeps(old, new)` (e.g. the max with `keeps = |a, b| a > b`).
+///
+/// ### Strategy
+/// An item that is pushed after an item `x` not kept by it, and that leaves the queue after
+/// `x`, can never be the answer again: it is evicted on push, from the back. The stored
+/// items are then monotone and the answer is at the front. Popping removes the oldest item
+/// of the queue, which is the front only if it wasn't already evicted.
+///
+/// ### Complexity
+/// `O(n)` space, `O(1)` amortized per operation.
+///
+pub struct MonotonicQueue<T, Cmp> {
+    deque: VecDeque<(usize, T)>,
+    pushed: usize,
+    popped: usize,
+    keeps: Cmp,
+}
+
+impl<T: Ord> MonotonicQueue<T, Keeps<T>> {
+    /// Queue whose `peek` is the max
+    pub fn max() -> Self {
+        Self::new(|a, b| a > b)
+    }
+
+    /// Queue whose `peek` is the min
+    pub fn min() -> Self {
+        Self::new(|a, b| a < b)
+    }
+}
+
+impl<T, Cmp: Fn(&T, &T) -> bool> MonotonicQueue<T, Cmp> {
+    pub fn new(keeps: Cmp) -> Self {
+        Self {
+            deque: VecDeque::new(),
+            pushed: 0,
+            popped: 0,
+            keeps,
+        }
+    }
+
+    /// Number of items in the queue (including the evicted ones not popped yet)
+    pub fn len(&self) -> usize {
+        self.pushed - self.popped
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /* PUBLIC API */
+    /// Pushes `v` at the back of the queue
+    pub fn push(&mut self, v: T) {
+        while let Some((_, back)) = self.deque.back()
+            && !(self.keeps)(back, &v)
+        {
+            self.deque.pop_back();
+        }
+        self.deque.push_back((self.pushed, v));
+        self.pushed += 1;
+    }
+
+    /* PUBLIC API */
+    ///
+    /// Removes the oldest item of the queue
+    ///
+    /// ## Panics
+    /// If the queue is empty
+    pub fn pop_front(&mut self) {
+        if self.is_empty() {
+            panic!("Queue is empty")
+        }
+        if let Some(&(i, _)) = self.deque.front()
+            && i == self.popped
+        {
+            self.deque.pop_front();
+        }
+        self.popped += 1;
+    }
+
+    /* PUBLIC API */
+    /// The best item of the queue
+    pub fn peek(&self) -> Option<&T> {
+        self.deque.front().map(|(_, v)| v)
+    }
+}
+
+///
+/// Lazy iterator over the best item of every window of `k` consecutive items of `iter`,
+/// see `sliding_by`
+pub struct Sliding<I: Iterator, Cmp> {
+    iter: I,
+    queue: MonotonicQueue<I::Item, Cmp>,
+    k: usize,
+}
+
+impl<I, Cmp> Iterator for Sliding<I, Cmp>
+where
+    I: Iterator,
+    I::Item: Clone,
+    Cmp: Fn(&I::Item, &I::Item) -> bool,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        //fill the window, then every step takes a single item from the source
+        while self.queue.len() < self.k {
+            self.queue.push(self.iter.next()?);
+        }
+        let best = self.queue.peek().cloned()?; //only with k = 0
+        self.queue.pop_front();
+        Some(best)
+    }
+}
+
+///
+/// Best item, according to `keeps` (as in `MonotonicQueue`), of every window of `k`
+/// consecutive items of `iter`. Items are consumed only when needed.
+///
+/// Yields nothing if `k == 0` or the source has less than `k` items.
+pub fn sliding_by<I, Cmp>(iter: I, k: usize, keeps: Cmp) -> Sliding<I::IntoIter, Cmp>
+where
+    I: IntoIterator,
+    Cmp: Fn(&I::Item, &I::Item) -> bool,
+{
+    Sliding {
+        iter: iter.into_iter(),
+        queue: MonotonicQueue::new(keeps),
+        k,
+    }
+}
+
+/// Max of every window of `k` consecutive items
+pub fn sliding_max<I>(iter: I, k: usize) -> Sliding<I::IntoIter, Keeps<I::Item>>
+where
+    I: IntoIterator,
+    I::Item: Ord,
+{
+    let keeps: Keeps<I::Item> = |a, b| a > b;
+    sliding_by(iter, k, keeps)
+}
+
+/// Min of every window of `k` consecutive items
+pub fn sliding_min<I>(iter: I, k: usize) -> Sliding<I::IntoIter, Keeps<I::Item>>
+where
+    I: IntoIterator,
+    I::Item: Ord,
+{
+    let keeps: Keeps<I::Item> = |a, b| a < b;
+    sliding_by(iter, k, keeps)
+}
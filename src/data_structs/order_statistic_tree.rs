@@ -0,0 +1,271 @@
+use crate::data_structs::treap::{Link, Priorities, drop_iteratively};
+use std::cmp::Ordering;
+use std::ops::{Bound, RangeBounds};
+
+///
+/// Node of an `OrderStatisticTree`, `size` is the number of keys in its subtree
+struct Node<T> {
+    key: T,
+    priority: u64,
+    size: usize,
+    left: Link<Node<T>>,
+    right: Link<Node<T>>,
+}
+
+fn size<T>(t: &Link<Node<T>>) -> usize {
+    t.as_ref().map_or(0, |n| n.size)
+}
+
+///
+/// ## Order Statistic Tree
+///
+/// Ordered set (like `BTreeSet`) that also answers in `O(log(n))`:
+/// - `rank(x)`: how many keys are `< x`
+/// - `select(k)`: the `k`-th smallest key (0-based)
+///
+/// ### Strategy (Treap)
+/// A treap is a binary search tree on the keys and a heap on random priorities: its shape is
+/// the one of a BST built inserting the keys by decreasing priority, so its height is
+/// `O(log(n))` in expectation whatever the insertion order.
+///
+/// It is maintained with two primitives: `split` (divides a tree in the keys that satisfy a
+/// predicate and the others) and `merge` (joins two trees where every key of the first is
+/// smaller). Every node also stores the size of its subtree, which drives `rank` and `select`.
+///
+/// Priorities come from a xorshift generator with a fixed seed, so runs are reproducible. The
+/// boxed nodes are dropped without recursion.
+///
+/// ### Complexity
+/// `O(n)` space, `O(log(n))` expected per operation, `O(log(n) + k)` to iterate over `k` keys.
+///
+pub struct OrderStatisticTree<T> {
+    root: Link<Node<T>>,
+    priorities: Priorities,
+}
+
+impl<T: Ord> Default for OrderStatisticTree<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for OrderStatisticTree<T> {
+    fn drop(&mut self) {
+        drop_iteratively(self.root.take(), |n| [n.left.take(), n.right.take()]);
+    }
+}
+
+impl<T: Ord> OrderStatisticTree<T> {
+    pub fn new() -> Self {
+        Self {
+            root: None,
+            priorities: Priorities::default(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        size(&self.root)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    fn update(node: &mut Node<T>) {
+        node.size = 1 + size(&node.left) + size(&node.right);
+    }
+
+    ///
+    /// Splits the tree `t` in the keys for which `goes_left` holds and the others
+    /// (`goes_left` must be monotone: true on a prefix of the keys)
+    fn split(t: Link<Node<T>>, goes_left: &impl Fn(&T) -> bool) -> (Link<Node<T>>, Link<Node<T>>) {
+        let Some(mut node) = t else {
+            return (None, None);
+        };
+        if goes_left(&node.key) {
+            let (l, r) = Self::split(node.right.take(), goes_left);
+            node.right = l;
+            Self::update(&mut node);
+            (Some(node), r)
+        } else {
+            let (l, r) = Self::split(node.left.take(), goes_left);
+            node.left = r;
+            Self::update(&mut node);
+            (l, Some(node))
+        }
+    }
+
+    /// Merges two trees, every key of `a` is smaller than every key of `b`
+    fn merge(a: Link<Node<T>>, b: Link<Node<T>>) -> Link<Node<T>> {
+        match (a, b) {
+            (None, t) | (t, None) => t,
+            (Some(mut a), Some(mut b)) => {
+                if a.priority > b.priority {
+                    a.right = Self::merge(a.right.take(), Some(b));
+                    Self::update(&mut a);
+                    Some(a)
+                } else {
+                    b.left = Self::merge(Some(a), b.left.take());
+                    Self::update(&mut b);
+                    Some(b)
+                }
+            }
+        }
+    }
+
+    /* PUBLIC API */
+    pub fn contains(&self, key: &T) -> bool {
+        let mut t = self.root.as_deref();
+        while let Some(node) = t {
+            match key.cmp(&node.key) {
+                Ordering::Less => t = node.left.as_deref(),
+                Ordering::Greater => t = node.right.as_deref(),
+                Ordering::Equal => return true,
+            }
+        }
+        false
+    }
+
+    /* PUBLIC API */
+    ///
+    /// Inserts a key
+    ///
+    /// ## Returns
+    /// `false` if the key was already in the tree
+    pub fn insert(&mut self, key: T) -> bool {
+        if self.contains(&key) {
+            return false;
+        }
+        let (l, r) = Self::split(self.root.take(), &|k: &T| *k < key);
+        let node = Box::new(Node {
+            key,
+            priority: self.priorities.next_priority(),
+            size: 1,
+            left: None,
+            right: None,
+        });
+        self.root = Self::merge(Self::merge(l, Some(node)), r);
+        true
+    }
+
+    /* PUBLIC API */
+    ///
+    /// Removes a key
+    ///
+    /// ## Returns
+    /// `false` if the key wasn't in the tree
+    pub fn remove(&mut self, key: &T) -> bool {
+        if !self.contains(key) {
+            return false;
+        }
+        let (l, r) = Self::split(self.root.take(), &|k: &T| k < key);
+        let (_, r) = Self::split(r, &|k: &T| k <= key); //a single node
+        self.root = Self::merge(l, r);
+        true
+    }
+
+    /* PUBLIC API */
+    /// Number of keys `< key`
+    pub fn rank(&self, key: &T) -> usize {
+        let (mut t, mut res) = (self.root.as_deref(), 0);
+        while let Some(node) = t {
+            if node.key < *key {
+                res += size(&node.left) + 1;
+                t = node.right.as_deref();
+            } else {
+                t = node.left.as_deref();
+            }
+        }
+        res
+    }
+
+    /* PUBLIC API */
+    ///
+    /// The `k`-th smallest key (0-based, `k = 0` is the minimum)
+    ///
+    /// ## Returns
+    /// `None` if the tree holds `k` keys or less
+    pub fn select(&self, k: usize) -> Option<&T> {
+        let (mut t, mut k) = (self.root.as_deref(), k);
+        while let Some(node) = t {
+            let left = size(&node.left);
+            if k < left {
+                t = node.left.as_deref();
+            } else if k == left {
+                return Some(&node.key);
+            } else {
+                k -= left + 1;
+                t = node.right.as_deref();
+            }
+        }
+        None
+    }
+
+    ///
+    /// In order iterator over the keys between `start` and `end`: we push the path to the
+    /// first key after `start`, skipping the subtrees below it
+    fn iter_between(&self, start: Bound<&T>, end: Bound<T>) -> Iter<'_, T> {
+        let mut stack = vec![];
+        let mut t = self.root.as_deref();
+        while let Some(node) = t {
+            let after_start = match start {
+                Bound::Included(lo) => node.key >= *lo,
+                Bound::Excluded(lo) => node.key > *lo,
+                Bound::Unbounded => true,
+            };
+            if after_start {
+                stack.push(node);
+                t = node.left.as_deref();
+            } else {
+                t = node.right.as_deref();
+            }
+        }
+        Iter { stack, end }
+    }
+
+    /* PUBLIC API */
+    /// Iterates over the keys in increasing order
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.iter_between(Bound::Unbounded, Bound::Unbounded)
+    }
+
+    /* PUBLIC API */
+    /// Iterates in increasing order over the keys in `range`
+    pub fn range<R: RangeBounds<T>>(&self, range: R) -> Iter<'_, T>
+    where
+        T: Clone,
+    {
+        self.iter_between(range.start_bound(), range.end_bound().cloned())
+    }
+}
+
+///
+/// In order iterator over the keys of an `OrderStatisticTree`: the stack holds the nodes
+/// whose key and right subtree are still to visit
+pub struct Iter<'a, T> {
+    stack: Vec<&'a Node<T>>,
+    end: Bound<T>,
+}
+
+impl<'a, T: Ord> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        let node = self.stack.pop()?;
+        let in_range = match &self.end {
+            Bound::Included(hi) => node.key <= *hi,
+            Bound::Excluded(hi) => node.key < *hi,
+            Bound::Unbounded => true,
+        };
+        if !in_range {
+            self.stack.clear();
+            return None;
+        }
+        let mut child = node.right.as_deref();
+        while let Some(c) = child {
+            self.stack.push(c);
+            child = c.left.as_deref();
+        }
+        Some(&node.key)
+    }
+}
@@ -0,0 +1,284 @@
+use crate::data_structs::monoid::{Monoid, Sum};
+
+///
+/// Node of a `PersistentSegmentTree`, children are indices in the node arena
+/// (unused on leaves)
+struct Node<V> {
+    val: V,
+    left: usize,
+    right: usize,
+}
+
+///
+/// ## Persistent Segment Tree
+///
+/// Segment tree generic over a `Monoid` that keeps every past version. An update never
+/// modifies a node: it copies the `O(log(n))` nodes on the path from the root to the leaf,
+/// and the copies point to the untouched subtrees of the previous version. So every
+/// version is a root in a shared arena of nodes.
+///
+/// Versions are numbered in creation order, the tree built from the initial slice is
+/// version `0`. Any version (not only the latest) can be updated, branching the history.
+///
+/// ### Complexity
+/// `O(n)` nodes for the initial version and `O(log(n))` more nodes per update. Updates and
+/// queries on any version cost `O(log(n))` monoid operations.
+///
+pub struct PersistentSegmentTree<M: Monoid> {
+    n: usize,
+    nodes: Vec<Node<M::Value>>,
+    roots: Vec<usize>,
+}
+
+impl<M: Monoid> PersistentSegmentTree<M> {
+    /// Number of elements in every version
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    /// Number of versions created so far
+    pub fn versions(&self) -> usize {
+        self.roots.len()
+    }
+
+    fn push_node(&mut self, val: M::Value, left: usize, right: usize) -> usize {
+        self.nodes.push(Node { val, left, right });
+        self.nodes.len() - 1
+    }
+
+    fn construct(&mut self, a: &[M::Value], left: usize, right: usize) -> usize {
+        if left == right {
+            return self.push_node(a[left].clone(), 0, 0);
+        }
+        let mid = (left + right) / 2;
+        let l = self.construct(a, left, mid);
+        let r = self.construct(a, mid + 1, right);
+        let val = M::combine(&self.nodes[l].val, &self.nodes[r].val);
+        self.push_node(val, l, r)
+    }
+
+    ///
+    /// Builds version `0` from a slice
+    ///
+    /// An empty slice produces an empty tree (with a version `0` that can't be queried)
+    pub fn build(a: &[M::Value]) -> Self {
+        let mut t = Self {
+            n: a.len(),
+            nodes: Vec::with_capacity(2 * a.len()),
+            roots: vec![],
+        };
+        let root = if a.is_empty() {
+            t.push_node(M::identity(), 0, 0)
+        } else {
+            t.construct(a, 0, a.len() - 1)
+        };
+        t.roots.push(root);
+        t
+    }
+
+    ///
+    /// Internal point update: returns the copy of `idx` where the leaf `pos` holds `val`
+    fn set_rec(
+        &mut self,
+        idx: usize,
+        left: usize,
+        right: usize,
+        pos: usize,
+        val: M::Value,
+    ) -> usize {
+        if left == right {
+            return self.push_node(val, 0, 0);
+        }
+        let mid = (left + right) / 2;
+        let (mut l, mut r) = (self.nodes[idx].left, self.nodes[idx].right);
+        if pos <= mid {
+            l = self.set_rec(l, left, mid, pos, val);
+        } else {
+            r = self.set_rec(r, mid + 1, right, pos, val);
+        }
+        let val = M::combine(&self.nodes[l].val, &self.nodes[r].val);
+        self.push_node(val, l, r)
+    }
+
+    fn query_rec(
+        &self,
+        idx: usize,
+        left: usize,
+        right: usize,
+        qleft: usize,
+        qright: usize,
+    ) -> M::Value {
+        if qleft > right || qright < left {
+            M::identity() //no overlap
+        } else if qleft <= left && qright >= right {
+            self.nodes[idx].val.clone() //total overlap
+        } else {
+            //partial overlap
+            let mid = (left + right) / 2;
+            let node = &self.nodes[idx];
+            let v_left = self.query_rec(node.left, left, mid, qleft, qright);
+            let v_right = self.query_rec(node.right, mid + 1, right, qleft, qright);
+            M::combine(&v_left, &v_right)
+        }
+    }
+
+    fn root(&self, version: usize) -> usize {
+        match self.roots.get(version) {
+            Some(&root) => root,
+            None => panic!("Version {version} does not exist"),
+        }
+    }
+
+    /* PUBLIC API */
+    ///
+    /// Creates a new version equal to `version` except for `a[i] = val`
+    ///
+    /// ## Returns
+    /// The number of the new version
+    pub fn set(&mut self, version: usize, i: usize, val: M::Value) -> usize {
+        if i >= self.n {
+            panic!("Index out of bounds")
+        }
+        let root = self.set_rec(self.root(version), 0, self.n - 1, i, val);
+        self.roots.push(root);
+        self.roots.len() - 1
+    }
+
+    /* PUBLIC API */
+    pub fn get(&self, version: usize, i: usize) -> M::Value {
+        self.query(version, i, i)
+    }
+
+    /* PUBLIC API */
+    ///
+    /// Aggregate of `a[l..=r]` as it was in `version`, a range with `l > r` is circular
+    /// (`a[l..n]` followed by `a[0..=r]`) as in `SegmentTree`
+    pub fn query(&self, version: usize, qleft: usize, qright: usize) -> M::Value {
+        if qleft >= self.n || qright >= self.n {
+            panic!("Range not supported")
+        }
+        let root = self.root(version);
+        if qleft <= qright {
+            self.query_rec(root, 0, self.n - 1, qleft, qright)
+        } else {
+            M::combine(
+                &self.query_rec(root, 0, self.n - 1, qleft, self.n - 1),
+                &self.query_rec(root, 0, self.n - 1, 0, qright),
+            )
+        }
+    }
+}
+
+///
+/// ## Range order statistics
+///
+/// Answers online, on a static sequence, "k-th smallest in `a[l..=r]`" and "how many
+/// values of `a[l..=r]` fall in a value range".
+///
+/// ### Strategy
+/// We compress the values to their rank among the distinct values, and we build a
+/// `PersistentSegmentTree<Sum<usize>>` over the ranks, where version `i` counts the
+/// occurrences of each rank in the prefix `a[0..i]`. Counts are additive, so the counts
+/// of `a[l..=r]` are the difference between version `r + 1` and version `l`.
+///
+/// For the k-th smallest we descend both versions together: if the left subtree (smaller
+/// ranks) holds more than `k` values of the range we go left, otherwise we skip them and
+/// go right.
+///
+/// ### Complexity
+/// `O(nlog(n))` time and space to build, `O(log(n))` per query.
+///
+pub struct RangeOrderStatistics<T> {
+    values: Vec<T>,
+    tree: PersistentSegmentTree<Sum<usize>>,
+}
+
+impl<T: Ord + Copy> RangeOrderStatistics<T> {
+    pub fn new(a: &[T]) -> Self {
+        let mut values = a.to_vec();
+        values.sort_unstable();
+        values.dedup();
+
+        let mut tree = PersistentSegmentTree::build(&vec![0; values.len()]);
+        for (i, v) in a.iter().enumerate() {
+            let rank = values.binary_search(v).unwrap();
+            let count = tree.get(i, rank);
+            tree.set(i, rank, count + 1); //version i + 1
+        }
+        Self { values, tree }
+    }
+
+    /// Number of values in the sequence
+    pub fn len(&self) -> usize {
+        self.tree.versions() - 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn check_range(&self, l: usize, r: usize) {
+        if l > r || r >= self.len() {
+            panic!("Range not supported")
+        }
+    }
+
+    /// Occurrences in `a[l..=r]` of values whose rank is `< rank`
+    fn count_ranks_below(&self, l: usize, r: usize, rank: usize) -> usize {
+        if rank == 0 {
+            0
+        } else {
+            self.tree.query(r + 1, 0, rank - 1) - self.tree.query(l, 0, rank - 1)
+        }
+    }
+
+    /* PUBLIC API */
+    ///
+    /// The `k`-th smallest value (0-based, `k = 0` is the minimum) in `a[l..=r]`
+    ///
+    /// ## Returns
+    /// `None` if the range holds `k` values or less
+    pub fn kth_smallest(&self, l: usize, r: usize, k: usize) -> Option<T> {
+        self.check_range(l, r);
+        if k > r - l {
+            return None;
+        }
+        let nodes = &self.tree.nodes;
+        let (mut u, mut v) = (self.tree.root(l), self.tree.root(r + 1));
+        let (mut left, mut right, mut k) = (0, self.values.len() - 1, k);
+        while left < right {
+            let mid = (left + right) / 2;
+            let in_left = nodes[nodes[v].left].val - nodes[nodes[u].left].val;
+            if k < in_left {
+                (u, v, right) = (nodes[u].left, nodes[v].left, mid);
+            } else {
+                k -= in_left;
+                (u, v, left) = (nodes[u].right, nodes[v].right, mid + 1);
+            }
+        }
+        Some(self.values[left])
+    }
+
+    /* PUBLIC API */
+    /// How many values of `a[l..=r]` are `<= x`
+    pub fn count_le(&self, l: usize, r: usize, x: T) -> usize {
+        self.check_range(l, r);
+        self.count_ranks_below(l, r, self.values.partition_point(|v| *v <= x))
+    }
+
+    /* PUBLIC API */
+    /// How many values of `a[l..=r]` fall in `[lo, hi]`
+    pub fn count_range(&self, l: usize, r: usize, lo: T, hi: T) -> usize {
+        self.check_range(l, r);
+        if lo > hi {
+            return 0;
+        }
+        let below_hi = self.values.partition_point(|v| *v <= hi);
+        let below_lo = self.values.partition_point(|v| *v < lo);
+        self.count_ranks_below(l, r, below_hi) - self.count_ranks_below(l, r, below_lo)
+    }
+}
@@ -0,0 +1,86 @@
+use std::ops::{Add, Sub};
+
+///
+/// ## 2D Prefix Sum
+///
+/// Rectangle sums on a static `rows x cols` grid, given flattened in row-major order (the cell
+/// `(r, c)` is at `r * cols + c`).
+///
+/// We store the flattened `(rows + 1) x (cols + 1)` matrix `P` where `P[i][j]` is the sum of
+/// the rectangle from `(0, 0)` to `(i - 1, j - 1)`: the leading row and column of zeroes
+/// avoid special cases on the borders. It is computed as
+///
+/// `P[i][j] = grid[i - 1][j - 1] + P[i - 1][j] + P[i][j - 1] - P[i - 1][j - 1]`
+///
+/// and any rectangle is the inclusion-exclusion of four entries.
+///
+/// ### Complexity
+/// `O(rows * cols)` space and build time, `O(1)` per query.
+///
+#[derive(Debug)]
+pub struct PrefixSum2D<T> {
+    rows: usize,
+    cols: usize,
+    prefix: Vec<T>,
+}
+
+impl<T> PrefixSum2D<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + Clone + Copy + Default,
+{
+    ///
+    /// Builds the prefix sums of a flattened grid
+    ///
+    /// ## Panics
+    /// If `grid.len() != rows * cols`
+    pub fn new(grid: &[T], rows: usize, cols: usize) -> Self {
+        assert_eq!(rows * cols, grid.len());
+        let width = cols + 1;
+        //the first row and column are 0, for ease in the next step
+        let mut prefix = vec![T::default(); (rows + 1) * width];
+        for i in 1..=rows {
+            //start index of the current and the previous row
+            let (base, prev_base) = (i * width, (i - 1) * width);
+            for j in 1..=cols {
+                prefix[base + j] =
+                    grid[(i - 1) * cols + (j - 1)] + prefix[prev_base + j] + prefix[base + j - 1]
+                        - prefix[prev_base + j - 1];
+            }
+        }
+        Self { rows, cols, prefix }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows == 0 || self.cols == 0
+    }
+
+    ///## Rectangle Sum
+    /// Sum of the cells `(r, c)` with `r1 <= r <= r2` and `c1 <= c <= c2`
+    pub fn rect_sum(&self, r1: usize, c1: usize, r2: usize, c2: usize) -> Result<T, &str> {
+        if r1 > r2 || c1 > c2 {
+            return Err("Range not supported");
+        } else if r2 >= self.rows || c2 >= self.cols {
+            return Err("Index overflow");
+        }
+        let width = self.cols + 1;
+        let (top, bottom) = (r1 * width, (r2 + 1) * width);
+        //adding before subtracting keeps unsigned types from underflowing
+        Ok(self.prefix[bottom + c2 + 1] + self.prefix[top + c1]
+            - self.prefix[top + c2 + 1]
+            - self.prefix[bottom + c1])
+    }
+
+    ///## Sum
+    /// Sum of the rectangle from `(0, 0)` to `(r, c)` (inclusive)
+    pub fn sum(&self, r: usize, c: usize) -> Result<T, &str> {
+        self.rect_sum(0, 0, r, c)
+    }
+}
@@ -1,196 +1,137 @@
-use std::ops::{Add};
-
-/**
- * Basic implementation of a segment tree of int64 that can
- * compute range min queries, and can update values in range
- * by addition
- */
-pub struct SegmentTree {
+use crate::data_structs::monoid::Monoid;
+use std::ops::Add;
+
+///
+/// Segment tree generic over a `Monoid`
+///
+/// The monoid supplies the element type, the identity and the (associative) combine
+/// operation, so the same structure computes range min, max, sum, gcd, xor or any custom
+/// aggregate. It supports point updates and range queries.
+///
+/// ## Circular ranges
+/// Both ends of a range are inclusive. A range `[l,r]` with `l > r` is treated as circular,
+/// so it covers `[l,n-1] u [0,r]` and the two parts are combined in this order.
+///
+/// ## Range updates
+/// This tree has no lazy tags, so it only updates single positions. The former `i64` range
+/// add API (`add_range`, `min_range`, `sum_range`) is replaced by
+/// `LazySegmentTree<Min<i64>, action::Add<i64>>` (or `Sum<i64>` for sums): `apply(l, r, v)`
+/// adds `v` to a range and `query(l, r)` aggregates it, with the same circular ranges.
+///
+/// ## Complexity
+/// The tree takes `O(n)` space (`2 * next_power_of_two(n) - 1` nodes), it's built in `O(n)`
+/// and each update or query costs `O(log(n))` monoid operations.
+///
+pub struct SegmentTree<M: Monoid> {
     n: usize,
-    pub tree: Vec<i64>,
-    pub lazy: Vec<i64>,
+    tree: Vec<M::Value>,
 }
 
-impl SegmentTree {
+impl<M: Monoid> SegmentTree<M> {
     pub fn len(&self) -> usize {
         self.n
     }
 
-    #[allow(unused)]
     pub fn is_empty(&self) -> bool {
         self.n == 0
     }
 
-    fn construct(a: &[i64], seg: &mut [i64], idx: usize, left: usize, right: usize) {
+    fn construct(a: &[M::Value], seg: &mut [M::Value], idx: usize, left: usize, right: usize) {
         if left == right {
-            seg[idx] = a[left];
+            seg[idx] = a[left].clone();
             return;
         }
         let mid = (left + right) / 2;
         Self::construct(a, seg, idx * 2 + 1, left, mid);
         Self::construct(a, seg, idx * 2 + 2, mid + 1, right);
-        seg[idx] = Ord::min(seg[idx * 2 + 1], seg[idx * 2 + 2]);
+        seg[idx] = M::combine(&seg[idx * 2 + 1], &seg[idx * 2 + 2]);
     }
 
-    pub fn build(a: &[i64]) -> Self {
+    ///
+    /// Builds the tree from a slice of values
+    ///
+    /// An empty slice produces an empty tree
+    pub fn build(a: &[M::Value]) -> Self {
         let n = a.len();
-        let mut pow_2 = 1usize;
-        while pow_2 < n {
-            pow_2 *= 2;
-        }
-        let ln = (pow_2 * 2) - 1;
-        let mut tree = vec![i64::MAX; ln];
-        let lazy = vec![0; ln];
-        Self::construct(a, &mut tree, 0, 0, n - 1);
-        Self { n, tree, lazy }
-    }
-
-    /// Push a pending addition down to the children of `idx`.
-    fn push(&mut self, idx: usize) {
-        //base case
-        let base = idx * 2;
-        //update the children
-        for child in [base + 1, base + 2] {
-            self.tree[child] += self.lazy[idx];
-            self.lazy[child] += self.lazy[idx];
+        let ln = (n.next_power_of_two() * 2) - 1;
+        let mut tree = vec![M::identity(); ln];
+        if n > 0 {
+            Self::construct(a, &mut tree, 0, 0, n - 1);
         }
-        //reset the lazy count
-        self.lazy[idx] = 0;
+        Self { n, tree }
     }
 
     ///
-    /// Internal range_add update that uses lazy propagation to
-    /// perform subtree update on demand
-    ///
-    fn range_add_rec(
-        &mut self,
-        idx: usize,
-        left: usize,
-        right: usize,
-        qleft: usize,
-        qright: usize,
-        val: i64,
-    ) {
-        //check overlaps
-        if qleft > right || qright < left {
-            return; //no overlap
-        }
-        if qleft <= left && qright >= right {
-            //total overlap
-            self.tree[idx] += val; //update the tree value
-            self.lazy[idx] += val; //set the lazy update for children
+    /// Internal point update: replaces the leaf at `pos` and recomputes its ancestors
+    /// on the way back from the recursion
+    fn set_rec(&mut self, idx: usize, left: usize, right: usize, pos: usize, val: M::Value) {
+        if left == right {
+            self.tree[idx] = val;
             return;
         }
-
-        //assure the children are updated
-        self.push(idx);
-        //compute the mid for recursion
         let mid = (left + right) / 2;
         let base = idx * 2;
-
-        //compute recursion on children
-        self.range_add_rec(base + 1, left, mid, qleft, qright, val);
-        self.range_add_rec(base + 2, mid + 1, right, qleft, qright, val);
-
-        //update the current node based on the new value of children
-        self.tree[idx] = Ord::min(self.tree[base + 1], self.tree[base + 2]);
-    }
-
-
-    /// 
-    /// Internal range sum query that uses lazy propagation to ensure 
-    /// consistency of updates to subtrees on demand
-    fn range_sum_rec(
-        &mut self,
-        idx: usize,
-        left: usize,
-        right: usize,
-        qleft: usize,
-        qright: usize
-    ) -> i64 {
-        if qleft > right || qright < left {
-            0
-        } else if qleft <= left && qright >= right {
-            self.tree[idx]
+        if pos <= mid {
+            self.set_rec(base + 1, left, mid, pos, val);
         } else {
-            self.push(idx); //ensure children are updated
-            let mid = (right + left) / 2;
-            //return sums of both subtrees
-            self.range_sum_rec(idx * 2 + 1,left,mid,qleft,qright) +
-            self.range_sum_rec(idx * 2 + 2,mid + 1,right,qleft,qright) 
+            self.set_rec(base + 2, mid + 1, right, pos, val);
         }
+        self.tree[idx] = M::combine(&self.tree[base + 1], &self.tree[base + 2]);
     }
 
     ///
-    /// Internal range min query that uses lazy propagation ensure consistency
-    /// of updates to subtrees on demand
-    ///
-    fn range_min_rec(
-        &mut self,
+    /// Internal range query, returns the identity on no overlap so that it can
+    /// be combined freely with the result of the sibling
+    fn query_rec(
+        &self,
         idx: usize,
         left: usize,
         right: usize,
         qleft: usize,
         qright: usize,
-    ) -> i64 {
+    ) -> M::Value {
         if qleft > right || qright < left {
-            return i64::MAX; //no overlap
+            M::identity() //no overlap
         } else if qleft <= left && qright >= right {
-            return self.tree[idx]; //total overlap
+            self.tree[idx].clone() //total overlap
+        } else {
+            //partial overlap
+            let mid = (left + right) / 2;
+            let v_left = self.query_rec(idx * 2 + 1, left, mid, qleft, qright);
+            let v_right = self.query_rec(idx * 2 + 2, mid + 1, right, qleft, qright);
+            M::combine(&v_left, &v_right)
         }
-
-        //partial overlap
-        self.push(idx); //ensure children are updated
-        let mid = (right + left) / 2;
-        let v_left = self.range_min_rec(idx * 2 + 1, left, mid, qleft, qright);
-        let v_right = self.range_min_rec(idx * 2 + 2, mid + 1, right, qleft, qright);
-        Ord::min(v_left, v_right)
     }
 
     /* PUBLIC API */
-    pub fn add_range(&mut self, qleft: usize, qright: usize, val: i64) {
-        if qleft >= (self.n) || qright >= (self.n) {
-            panic!("Range not supported")
-        }
-        if qleft <= qright {
-            self.range_add_rec(0, 0, self.n - 1, qleft, qright, val)
-        } else {
-            self.range_add_rec(0, 0, self.n - 1, qleft, self.n - 1, val);
-            self.range_add_rec(0, 0, self.n - 1, 0, qright, val);
+    pub fn set(&mut self, i: usize, val: M::Value) {
+        if i >= self.n {
+            panic!("Index out of bounds")
         }
+        self.set_rec(0, 0, self.n - 1, i, val)
     }
 
     /* PUBLIC API */
-    pub fn min_range(&mut self, qleft: usize, qright: usize) -> i64 {
-        if qleft >= (self.n) || qright >= (self.n) {
-            panic!("Range not supported")
-        }
-        if qleft <= qright {
-            self.range_min_rec(0, 0, self.n - 1, qleft, qright)
-        } else {
-            Ord::min(
-                self.range_min_rec(0, 0, self.n - 1, qleft, self.n - 1),
-                self.range_min_rec(0, 0, self.n - 1, 0, qright),
-            )
-        }
+    pub fn get(&self, i: usize) -> M::Value {
+        self.query(i, i)
     }
 
     /* PUBLIC API */
-    pub fn sum_range(&mut self, qleft: usize, qright: usize) -> i64 {
+    pub fn query(&self, qleft: usize, qright: usize) -> M::Value {
         if qleft >= (self.n) || qright >= (self.n) {
             panic!("Range not supported")
         }
         if qleft <= qright {
-            self.range_sum_rec(0, 0, self.n - 1, qleft, qright)
+            self.query_rec(0, 0, self.n - 1, qleft, qright)
         } else {
-            self.range_sum_rec(0, 0, self.n - 1, qleft, self.n - 1) +
-            self.range_sum_rec(0, 0, self.n - 1, 0, qright)
+            M::combine(
+                &self.query_rec(0, 0, self.n - 1, qleft, self.n - 1),
+                &self.query_rec(0, 0, self.n - 1, 0, qright),
+            )
         }
     }
 }
 
-
-
 pub struct SegmentTreeSum<T> {
     pub tree: Vec<Option<T>>,
     ln: usize,
@@ -0,0 +1,339 @@
+///
+/// Node of a `SegmentTreeBeats`: besides the sum, it stores for both ends of the segment
+/// the extreme value, the strict second extreme and the number of occurrences of the
+/// extreme. Second extremes are `i64::MIN` (`i64::MAX` for the min) if every value in the
+/// segment is the same.
+///
+#[derive(Clone, Copy, Debug, Default)]
+struct Node {
+    sum: i64,
+    max1: i64,
+    max2: i64,
+    max_cnt: i64,
+    min1: i64,
+    min2: i64,
+    min_cnt: i64,
+    add: i64,
+}
+
+impl Node {
+    fn leaf(v: i64) -> Self {
+        Self {
+            sum: v,
+            max1: v,
+            max2: i64::MIN,
+            max_cnt: 1,
+            min1: v,
+            min2: i64::MAX,
+            min_cnt: 1,
+            add: 0,
+        }
+    }
+
+    ///
+    /// Merges 2 children into their parent node (the pending addition of the parent
+    /// is reset, since the children are up to date)
+    fn merge(l: &Node, r: &Node) -> Self {
+        let (max1, max2, max_cnt) = if l.max1 == r.max1 {
+            (l.max1, l.max2.max(r.max2), l.max_cnt + r.max_cnt)
+        } else if l.max1 > r.max1 {
+            (l.max1, l.max2.max(r.max1), l.max_cnt)
+        } else {
+            (r.max1, r.max2.max(l.max1), r.max_cnt)
+        };
+        let (min1, min2, min_cnt) = if l.min1 == r.min1 {
+            (l.min1, l.min2.min(r.min2), l.min_cnt + r.min_cnt)
+        } else if l.min1 < r.min1 {
+            (l.min1, l.min2.min(r.min1), l.min_cnt)
+        } else {
+            (r.min1, r.min2.min(l.min1), r.min_cnt)
+        };
+        Self {
+            sum: l.sum + r.sum,
+            max1,
+            max2,
+            max_cnt,
+            min1,
+            min2,
+            min_cnt,
+            add: 0,
+        }
+    }
+
+    /// Adds `v` to every element of a segment of `len` elements
+    fn apply_add(&mut self, v: i64, len: usize) {
+        self.sum += v * len as i64;
+        self.max1 += v;
+        if self.max2 != i64::MIN {
+            self.max2 += v;
+        }
+        self.min1 += v;
+        if self.min2 != i64::MAX {
+            self.min2 += v;
+        }
+        self.add += v;
+    }
+
+    ///
+    /// Lowers the max of the segment to `x`, requires `max2 < x < max1` so that only
+    /// the `max_cnt` occurrences of the max are affected
+    fn apply_chmin(&mut self, x: i64) {
+        self.sum -= (self.max1 - x) * self.max_cnt;
+        if self.min1 == self.max1 {
+            self.min1 = x;
+        } else if self.min2 == self.max1 {
+            self.min2 = x;
+        }
+        self.max1 = x;
+    }
+
+    ///
+    /// Raises the min of the segment to `x`, requires `min1 < x < min2` so that only
+    /// the `min_cnt` occurrences of the min are affected
+    fn apply_chmax(&mut self, x: i64) {
+        self.sum += (x - self.min1) * self.min_cnt;
+        if self.max1 == self.min1 {
+            self.max1 = x;
+        } else if self.max2 == self.min1 {
+            self.max2 = x;
+        }
+        self.min1 = x;
+    }
+}
+
+///
+/// ## Segment Tree Beats
+///
+/// Segment tree over `i64` that supports on a range `[l,r]`:
+/// - `chmin(l,r,x)`: `a[i] <- min(a[i], x)`
+/// - `chmax(l,r,x)`: `a[i] <- max(a[i], x)`
+/// - `add(l,r,x)`: `a[i] <- a[i] + x`
+/// - `sum`, `max` and `min` queries
+///
+/// ### Strategy (Ji Driver)
+/// A lazy tag can't describe a chmin on a sum: the new sum depends on how many values exceed
+/// `x`. So every node stores the max, the strict second max and the count of the max (and
+/// symmetrically for the min). A `chmin(x)` on a totally overlapped node:
+/// - does nothing if `max <= x`
+/// - if `second_max < x < max` only the occurrences of the max change, so we update the
+///   node in `O(1)` (`sum -= (max - x) * count`) and the children will inherit the new max
+///   when pushed
+/// - otherwise we recurse on the children (the "beat")
+///
+/// Every beat merges at least two distinct values of the node, which bounds the extra work.
+///
+/// ### Complexity
+/// `O(n)` space and build time. Queries cost `O(log(n))`, updates cost amortized
+/// `O(log(n))` with only chmin/chmax and amortized `O(log^2(n))` mixed with range additions.
+///
+/// Ranges follow the convention of `SegmentTree`: inclusive, and circular when `l > r`.
+///
+pub struct SegmentTreeBeats {
+    n: usize,
+    tree: Vec<Node>,
+}
+
+impl SegmentTreeBeats {
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    fn construct(a: &[i64], seg: &mut [Node], idx: usize, left: usize, right: usize) {
+        if left == right {
+            seg[idx] = Node::leaf(a[left]);
+            return;
+        }
+        let mid = (left + right) / 2;
+        Self::construct(a, seg, idx * 2 + 1, left, mid);
+        Self::construct(a, seg, idx * 2 + 2, mid + 1, right);
+        seg[idx] = Node::merge(&seg[idx * 2 + 1], &seg[idx * 2 + 2]);
+    }
+
+    ///
+    /// Builds the tree from a slice
+    ///
+    /// An empty slice produces an empty tree
+    pub fn build(a: &[i64]) -> Self {
+        let n = a.len();
+        let ln = (n.next_power_of_two() * 2) - 1;
+        let mut tree = vec![Node::default(); ln];
+        if n > 0 {
+            Self::construct(a, &mut tree, 0, 0, n - 1);
+        }
+        Self { n, tree }
+    }
+
+    fn pull(&mut self, idx: usize) {
+        self.tree[idx] = Node::merge(&self.tree[idx * 2 + 1], &self.tree[idx * 2 + 2]);
+    }
+
+    ///
+    /// Push the pending addition and the bounds of `idx` down to its children: a child
+    /// whose max exceeds the parent max missed a chmin (its second max is below the
+    /// parent max by construction), symmetrically for the min.
+    fn push(&mut self, idx: usize, left: usize, right: usize) {
+        let node = self.tree[idx];
+        let mid = (left + right) / 2;
+        for (child, len) in [(idx * 2 + 1, mid - left + 1), (idx * 2 + 2, right - mid)] {
+            let c = &mut self.tree[child];
+            if node.add != 0 {
+                c.apply_add(node.add, len);
+            }
+            if c.max1 > node.max1 {
+                c.apply_chmin(node.max1);
+            }
+            if c.min1 < node.min1 {
+                c.apply_chmax(node.min1);
+            }
+        }
+        self.tree[idx].add = 0;
+    }
+
+    fn chmin_rec(&mut self, idx: usize, left: usize, right: usize, ql: usize, qr: usize, x: i64) {
+        if ql > right || qr < left || self.tree[idx].max1 <= x {
+            return; //no overlap or nothing to clamp
+        }
+        if ql <= left && qr >= right && self.tree[idx].max2 < x {
+            //total overlap, only the max changes
+            self.tree[idx].apply_chmin(x);
+            return;
+        }
+        //partial overlap or beat
+        self.push(idx, left, right);
+        let mid = (left + right) / 2;
+        self.chmin_rec(idx * 2 + 1, left, mid, ql, qr, x);
+        self.chmin_rec(idx * 2 + 2, mid + 1, right, ql, qr, x);
+        self.pull(idx);
+    }
+
+    fn chmax_rec(&mut self, idx: usize, left: usize, right: usize, ql: usize, qr: usize, x: i64) {
+        if ql > right || qr < left || self.tree[idx].min1 >= x {
+            return; //no overlap or nothing to clamp
+        }
+        if ql <= left && qr >= right && self.tree[idx].min2 > x {
+            //total overlap, only the min changes
+            self.tree[idx].apply_chmax(x);
+            return;
+        }
+        //partial overlap or beat
+        self.push(idx, left, right);
+        let mid = (left + right) / 2;
+        self.chmax_rec(idx * 2 + 1, left, mid, ql, qr, x);
+        self.chmax_rec(idx * 2 + 2, mid + 1, right, ql, qr, x);
+        self.pull(idx);
+    }
+
+    fn add_rec(&mut self, idx: usize, left: usize, right: usize, ql: usize, qr: usize, x: i64) {
+        if ql > right || qr < left {
+            return; //no overlap
+        }
+        if ql <= left && qr >= right {
+            //total overlap
+            self.tree[idx].apply_add(x, right - left + 1);
+            return;
+        }
+        //partial overlap
+        self.push(idx, left, right);
+        let mid = (left + right) / 2;
+        self.add_rec(idx * 2 + 1, left, mid, ql, qr, x);
+        self.add_rec(idx * 2 + 2, mid + 1, right, ql, qr, x);
+        self.pull(idx);
+    }
+
+    ///
+    /// Internal range query: folds with `merge` the nodes that are totally overlapped,
+    /// `None` stands for no overlap
+    fn query_rec(
+        &mut self,
+        idx: usize,
+        left: usize,
+        right: usize,
+        ql: usize,
+        qr: usize,
+    ) -> Option<Node> {
+        if ql > right || qr < left {
+            None
+        } else if ql <= left && qr >= right {
+            Some(self.tree[idx])
+        } else {
+            self.push(idx, left, right);
+            let mid = (left + right) / 2;
+            let v_left = self.query_rec(idx * 2 + 1, left, mid, ql, qr);
+            let v_right = self.query_rec(idx * 2 + 2, mid + 1, right, ql, qr);
+            match (v_left, v_right) {
+                (Some(l), Some(r)) => Some(Node::merge(&l, &r)),
+                (Some(v), None) | (None, Some(v)) => Some(v),
+                (None, None) => None,
+            }
+        }
+    }
+
+    /// Splits a (possibly circular) range in at most 2 non circular ones
+    fn ranges(&self, l: usize, r: usize) -> Vec<(usize, usize)> {
+        if l >= self.n || r >= self.n {
+            panic!("Range not supported")
+        }
+        if l <= r {
+            vec![(l, r)]
+        } else {
+            vec![(l, self.n - 1), (0, r)]
+        }
+    }
+
+    fn query(&mut self, l: usize, r: usize) -> Node {
+        let mut res: Option<Node> = None;
+        for (ql, qr) in self.ranges(l, r) {
+            let node = self.query_rec(0, 0, self.n - 1, ql, qr).unwrap();
+            res = Some(match res {
+                Some(prev) => Node::merge(&prev, &node),
+                None => node,
+            });
+        }
+        res.unwrap()
+    }
+
+    /* PUBLIC API */
+    pub fn chmin(&mut self, l: usize, r: usize, x: i64) {
+        for (ql, qr) in self.ranges(l, r) {
+            self.chmin_rec(0, 0, self.n - 1, ql, qr, x);
+        }
+    }
+
+    /* PUBLIC API */
+    pub fn chmax(&mut self, l: usize, r: usize, x: i64) {
+        for (ql, qr) in self.ranges(l, r) {
+            self.chmax_rec(0, 0, self.n - 1, ql, qr, x);
+        }
+    }
+
+    /* PUBLIC API */
+    pub fn add(&mut self, l: usize, r: usize, x: i64) {
+        for (ql, qr) in self.ranges(l, r) {
+            self.add_rec(0, 0, self.n - 1, ql, qr, x);
+        }
+    }
+
+    /* PUBLIC API */
+    pub fn sum(&mut self, l: usize, r: usize) -> i64 {
+        self.query(l, r).sum
+    }
+
+    /* PUBLIC API */
+    pub fn max(&mut self, l: usize, r: usize) -> i64 {
+        self.query(l, r).max1
+    }
+
+    /* PUBLIC API */
+    pub fn min(&mut self, l: usize, r: usize) -> i64 {
+        self.query(l, r).min1
+    }
+
+    /* PUBLIC API */
+    pub fn get(&mut self, i: usize) -> i64 {
+        self.sum(i, i)
+    }
+}
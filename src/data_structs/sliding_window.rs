@@ -0,0 +1,148 @@
+use crate::data_structs::monoid::Monoid;
+
+///
+/// ## Sliding Window (two-stack queue)
+///
+/// FIFO queue of values of a `Monoid` that answers the aggregate of all its values, in
+/// order, at any time. Unlike the monotonic queue it works for any associative operation,
+/// including non idempotent (sum) and non commutative ones (matrix product).
+///
+/// ### Strategy
+/// The queue is split in two stacks:
+/// - `back` receives the pushed values, and keeps the aggregate of all of them
+/// - `front` holds the oldest values (the oldest on top), and every item stores the
+///   aggregate from its value to the bottom of the stack
+///
+/// So `fold` is the aggregate on top of `front` combined with the one of `back`.
+/// `pop_front` pops from `front`; when it is empty the whole `back` is moved onto it,
+/// reversing the order and computing the aggregates.
+///
+/// ### Complexity
+/// `O(n)` space, `O(1)` amortized per operation: every value is moved at most once.
+///
+pub struct SlidingWindow<M: Monoid> {
+    //(value, aggregate of value and of the items below)
+    front: Vec<(M::Value, M::Value)>,
+    back: Vec<M::Value>,
+    back_agg: M::Value,
+}
+
+impl<M: Monoid> Default for SlidingWindow<M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<M: Monoid> SlidingWindow<M> {
+    pub fn new() -> Self {
+        Self {
+            front: vec![],
+            back: vec![],
+            back_agg: M::identity(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.front.len() + self.back.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Moves every value of `back` onto `front`
+    fn transfer(&mut self) {
+        while let Some(v) = self.back.pop() {
+            let agg = match self.front.last() {
+                Some((_, below)) => M::combine(&v, below),
+                None => v.clone(),
+            };
+            self.front.push((v, agg));
+        }
+        self.back_agg = M::identity();
+    }
+
+    /* PUBLIC API */
+    /// Pushes `v` at the back of the window
+    pub fn push(&mut self, v: M::Value) {
+        self.back_agg = M::combine(&self.back_agg, &v);
+        self.back.push(v);
+    }
+
+    /* PUBLIC API */
+    /// Removes the oldest value of the window, `None` if it is empty
+    pub fn pop_front(&mut self) -> Option<M::Value> {
+        if self.front.is_empty() {
+            self.transfer();
+        }
+        self.front.pop().map(|(v, _)| v)
+    }
+
+    /* PUBLIC API */
+    /// Aggregate of the values in the window, from the oldest to the newest
+    pub fn fold(&self) -> M::Value {
+        match self.front.last() {
+            Some((_, agg)) => M::combine(agg, &self.back_agg),
+            None => self.back_agg.clone(),
+        }
+    }
+}
+
+impl<M: Monoid> Extend<M::Value> for SlidingWindow<M> {
+    fn extend<I: IntoIterator<Item = M::Value>>(&mut self, iter: I) {
+        for v in iter {
+            self.push(v);
+        }
+    }
+}
+
+impl<M: Monoid> FromIterator<M::Value> for SlidingWindow<M> {
+    fn from_iter<I: IntoIterator<Item = M::Value>>(iter: I) -> Self {
+        let mut w = Self::new();
+        w.extend(iter);
+        w
+    }
+}
+
+///
+/// Lazy iterator over the aggregates of every window of `k` consecutive values of a
+/// source, see `fold_windows`
+pub struct FoldWindows<M: Monoid, I> {
+    iter: I,
+    window: SlidingWindow<M>,
+    k: usize,
+}
+
+impl<M: Monoid, I: Iterator<Item = M::Value>> Iterator for FoldWindows<M, I> {
+    type Item = M::Value;
+
+    fn next(&mut self) -> Option<M::Value> {
+        if self.k == 0 {
+            return None;
+        }
+        //the first window is filled at once, then every step slides by one value
+        while self.window.len() < self.k {
+            self.window.push(self.iter.next()?);
+        }
+        let res = self.window.fold();
+        self.window.pop_front();
+        Some(res)
+    }
+}
+
+///
+/// Aggregates of every window of `k` consecutive values of `iter`, computed online: values
+/// are consumed only when needed, so the source can be an unbounded stream.
+///
+/// Yields nothing if `k == 0` or the source has less than `k` values.
+pub fn fold_windows<M, I>(iter: I, k: usize) -> FoldWindows<M, I::IntoIter>
+where
+    M: Monoid,
+    I: IntoIterator<Item = M::Value>,
+{
+    FoldWindows {
+        iter: iter.into_iter(),
+        window: SlidingWindow::new(),
+        k,
+    }
+}
@@ -0,0 +1,76 @@
+///
+/// ## Sparse Table
+///
+/// Range queries in `O(1)` on a static sequence, for any associative and idempotent
+/// operation `op` (`op(x, x) = x`): min, max, gcd, bitwise and/or.
+///
+/// ### Strategy
+/// Level `k` of the table stores, for every `i`, `op` over the `2^k` items starting at `i`:
+///
+/// `table[k][i] = op(table[k - 1][i], table[k - 1][i + 2^(k - 1)])`
+///
+/// A range `[l,r]` of length `len` is covered by the two (overlapping) blocks of size
+/// `2^floor(log2(len))` starting at `l` and ending at `r`. Idempotence makes the overlap
+/// harmless, so a query is a single `op`.
+///
+/// Ranges follow the convention of `SegmentTree`: inclusive, and circular when `l > r`
+/// (the two sides of the wrap are combined with one more `op`).
+///
+/// ### Complexity
+/// `O(nlog(n))` space and build time, `O(1)` per query.
+///
+pub struct SparseTable<T, F> {
+    table: Vec<Vec<T>>,
+    op: F,
+}
+
+impl<T, F> SparseTable<T, F>
+where
+    T: Clone,
+    F: Fn(&T, &T) -> T,
+{
+    ///
+    /// Builds the table of a slice for the operation `op`
+    ///
+    /// An empty slice produces an empty table
+    pub fn new(a: &[T], op: F) -> Self {
+        let mut table = vec![a.to_vec()];
+        let mut half = 1;
+        while 2 * half <= a.len() {
+            let prev = table.last().unwrap();
+            let level = (0..=a.len() - 2 * half)
+                .map(|i| op(&prev[i], &prev[i + half]))
+                .collect();
+            table.push(level);
+            half *= 2;
+        }
+        Self { table, op }
+    }
+
+    pub fn len(&self) -> usize {
+        self.table[0].len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Internal query on the non circular range `[l,r]`
+    fn fold(&self, l: usize, r: usize) -> T {
+        let k = (r - l + 1).ilog2() as usize;
+        (self.op)(&self.table[k][l], &self.table[k][r + 1 - (1 << k)])
+    }
+
+    /* PUBLIC API */
+    pub fn query(&self, l: usize, r: usize) -> T {
+        let n = self.len();
+        if l >= n || r >= n {
+            panic!("Range not supported")
+        }
+        if l <= r {
+            self.fold(l, r)
+        } else {
+            (self.op)(&self.fold(l, n - 1), &self.fold(0, r))
+        }
+    }
+}
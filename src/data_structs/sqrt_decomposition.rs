@@ -0,0 +1,287 @@
+use crate::data_structs::action::scaled;
+use std::ops::{Add, Mul};
+
+///
+/// Aggregates of a block (or of a range) together with the pending tags of the block:
+/// the real value of an item of the block is `assign.unwrap_or(item) + add`
+#[derive(Clone, Copy, Debug)]
+struct Block<T> {
+    sum: T,
+    min: T,
+    max: T,
+    add: T,
+    assign: Option<T>,
+}
+
+impl<T> Block<T>
+where
+    T: Add<Output = T> + Ord + Copy + Default,
+{
+    fn merge(&self, other: &Self) -> Self {
+        Self {
+            sum: self.sum + other.sum,
+            min: self.min.min(other.min),
+            max: self.max.max(other.max),
+            add: T::default(),
+            assign: None,
+        }
+    }
+}
+
+///
+/// ## Sqrt Decomposition
+///
+/// Splits a sequence in blocks of `block_size` items (the last one can be shorter) and keeps
+/// for every block its sum, min and max. Supports on a range `[l,r]`:
+/// - `add(l,r,v)`: `a[i] <- a[i] + v`
+/// - `assign(l,r,v)`: `a[i] <- v`
+/// - `sum`, `min` and `max` queries
+///
+/// ### Strategy
+/// A range is made of some partial blocks at its ends and of whole blocks in the middle.
+/// Whole blocks are updated in `O(1)` with a lazy tag (pending assignment, then pending
+/// addition) and answer with their aggregates. Partial blocks are rebuilt item by item:
+/// before touching an item the pending tags of its block are pushed to the items.
+///
+/// Ranges follow the convention of `SegmentTree`: inclusive, and circular when `l > r`.
+///
+/// ### Complexity
+/// `O(n)` space and build time. With blocks of size `b` every update or query costs
+/// `O(b + n/b)`, that is `O(sqrt(n))` with the default `b = ceil(sqrt(n))`.
+///
+pub struct SqrtDecomposition<T> {
+    block_size: usize,
+    items: Vec<T>,
+    blocks: Vec<Block<T>>,
+}
+
+impl<T> SqrtDecomposition<T>
+where
+    T: Add<Output = T> + Mul<Output = T> + TryFrom<usize> + Ord + Copy + Default,
+{
+    ///
+    /// Builds the structure from a slice with blocks of `ceil(sqrt(n))` items
+    ///
+    /// An empty slice produces an empty structure
+    pub fn build(a: &[T]) -> Self {
+        let block_size = a.len().isqrt().max(1);
+        let block_size = if block_size * block_size < a.len() {
+            block_size + 1
+        } else {
+            block_size
+        };
+        Self::with_block_size(a, block_size)
+    }
+
+    ///
+    /// Builds the structure from a slice with blocks of `block_size` items
+    ///
+    /// ## Panics
+    /// If `block_size == 0`
+    pub fn with_block_size(a: &[T], block_size: usize) -> Self {
+        if block_size == 0 {
+            panic!("Block size must be positive")
+        }
+        let mut s = Self {
+            block_size,
+            items: a.to_vec(),
+            blocks: Vec::with_capacity(a.len().div_ceil(block_size)),
+        };
+        for b in 0..a.len().div_ceil(block_size) {
+            s.blocks.push(s.summarize(s.block_range(b)));
+        }
+        s
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn block_size(&self) -> usize {
+        self.block_size
+    }
+
+    /// Indices `[start,end]` of the items of block `b`
+    fn block_range(&self, b: usize) -> (usize, usize) {
+        let start = b * self.block_size;
+        (start, (start + self.block_size).min(self.len()) - 1)
+    }
+
+    /// Aggregates of the items in `[l,r]`, read without the pending tags of their block
+    fn summarize(&self, (l, r): (usize, usize)) -> Block<T> {
+        let first = self.items[l];
+        let mut res = Block {
+            sum: first,
+            min: first,
+            max: first,
+            add: T::default(),
+            assign: None,
+        };
+        for &v in &self.items[l + 1..=r] {
+            res.sum = res.sum + v;
+            res.min = res.min.min(v);
+            res.max = res.max.max(v);
+        }
+        res
+    }
+
+    /// Writes the pending tags of block `b` into its items
+    fn push(&mut self, b: usize) {
+        let Block { add, assign, .. } = self.blocks[b];
+        if assign.is_none() && add == T::default() {
+            return;
+        }
+        let (start, end) = self.block_range(b);
+        for v in &mut self.items[start..=end] {
+            *v = assign.unwrap_or(*v) + add;
+        }
+        self.blocks[b].add = T::default();
+        self.blocks[b].assign = None;
+    }
+
+    ///
+    /// Internal update on the non circular range `[l,r]`: `on_items` rewrites the items of
+    /// partial blocks, `on_block` tags whole blocks (given their length)
+    fn update(
+        &mut self,
+        l: usize,
+        r: usize,
+        on_items: impl Fn(&mut T),
+        on_block: impl Fn(&mut Block<T>, usize),
+    ) {
+        let (first, last) = (l / self.block_size, r / self.block_size);
+        for b in first..=last {
+            let (start, end) = self.block_range(b);
+            if l <= start && end <= r {
+                on_block(&mut self.blocks[b], end - start + 1);
+            } else {
+                self.push(b);
+                self.items[l.max(start)..=r.min(end)]
+                    .iter_mut()
+                    .for_each(&on_items);
+                self.blocks[b] = self.summarize((start, end));
+            }
+        }
+    }
+
+    /// Internal query on the non circular range `[l,r]`
+    fn fold(&self, l: usize, r: usize) -> Block<T> {
+        let (first, last) = (l / self.block_size, r / self.block_size);
+        let mut res: Option<Block<T>> = None;
+        for b in first..=last {
+            let (start, end) = self.block_range(b);
+            let part = if l <= start && end <= r {
+                self.blocks[b]
+            } else {
+                //partial block: read the items through the pending tags
+                let Block { add, assign, .. } = self.blocks[b];
+                let (ql, qr) = (l.max(start), r.min(end));
+                match assign {
+                    Some(v) => {
+                        let v = v + add;
+                        Block {
+                            sum: scaled(v, qr - ql + 1),
+                            min: v,
+                            max: v,
+                            add: T::default(),
+                            assign: None,
+                        }
+                    }
+                    None => {
+                        let mut part = self.summarize((ql, qr));
+                        part.sum = part.sum + scaled(add, qr - ql + 1);
+                        part.min = part.min + add;
+                        part.max = part.max + add;
+                        part
+                    }
+                }
+            };
+            res = Some(match res {
+                Some(prev) => prev.merge(&part),
+                None => part,
+            });
+        }
+        res.unwrap()
+    }
+
+    /// Splits a (possibly circular) range in at most 2 non circular ones
+    fn ranges(&self, l: usize, r: usize) -> Vec<(usize, usize)> {
+        if l >= self.len() || r >= self.len() {
+            panic!("Range not supported")
+        }
+        if l <= r {
+            vec![(l, r)]
+        } else {
+            vec![(l, self.len() - 1), (0, r)]
+        }
+    }
+
+    fn query(&self, l: usize, r: usize) -> Block<T> {
+        self.ranges(l, r)
+            .into_iter()
+            .map(|(ql, qr)| self.fold(ql, qr))
+            .reduce(|a, b| a.merge(&b))
+            .unwrap()
+    }
+
+    /* PUBLIC API */
+    pub fn add(&mut self, l: usize, r: usize, v: T) {
+        for (ql, qr) in self.ranges(l, r) {
+            self.update(
+                ql,
+                qr,
+                |x| *x = *x + v,
+                |block, len| {
+                    block.sum = block.sum + scaled(v, len);
+                    block.min = block.min + v;
+                    block.max = block.max + v;
+                    block.add = block.add + v;
+                },
+            );
+        }
+    }
+
+    /* PUBLIC API */
+    pub fn assign(&mut self, l: usize, r: usize, v: T) {
+        for (ql, qr) in self.ranges(l, r) {
+            self.update(
+                ql,
+                qr,
+                |x| *x = v,
+                |block, len| {
+                    *block = Block {
+                        sum: scaled(v, len),
+                        min: v,
+                        max: v,
+                        add: T::default(),
+                        assign: Some(v),
+                    }
+                },
+            );
+        }
+    }
+
+    /* PUBLIC API */
+    pub fn sum(&self, l: usize, r: usize) -> T {
+        self.query(l, r).sum
+    }
+
+    /* PUBLIC API */
+    pub fn min(&self, l: usize, r: usize) -> T {
+        self.query(l, r).min
+    }
+
+    /* PUBLIC API */
+    pub fn max(&self, l: usize, r: usize) -> T {
+        self.query(l, r).max
+    }
+
+    /* PUBLIC API */
+    pub fn get(&self, i: usize) -> T {
+        self.sum(i, i)
+    }
+}
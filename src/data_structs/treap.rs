@@ -0,0 +1,38 @@
+///
+/// Link to a subtree of a treap, shared by `OrderStatisticTree` and `ImplicitTreap`
+pub(crate) type Link<N> = Option<Box<N>>;
+
+///
+/// Xorshift generator of the priorities of the nodes of a treap. The seed is fixed, so runs
+/// are reproducible.
+pub(crate) struct Priorities(u64);
+
+impl Default for Priorities {
+    fn default() -> Self {
+        Self(0x2545_F491_4F6C_DD1D)
+    }
+}
+
+impl Priorities {
+    pub(crate) fn next_priority(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// A generator with a different sequence, for a treap split from the one using `self`
+    pub(crate) fn fork(&self) -> Self {
+        Self(self.0.rotate_left(32) ^ 0x9E37_79B9_7F4A_7C15)
+    }
+}
+
+///
+/// Drops the nodes of a treap with an explicit stack: `children` detaches the children of a
+/// node, so that no node is dropped with its subtree still attached
+pub(crate) fn drop_iteratively<N>(root: Link<N>, children: impl Fn(&mut N) -> [Link<N>; 2]) {
+    let mut stack: Vec<Box<N>> = root.into_iter().collect();
+    while let Some(mut node) = stack.pop() {
+        stack.extend(children(&mut node).into_iter().flatten());
+    }
+}
@@ -1,3 +1,8 @@
+use crate::data_structs::binary_tree;
+use hands_on_1::Tree;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
 #[macro_export]
 macro_rules! tree {
     ($val:expr, $left:expr, $right:expr) => {
@@ -26,12 +31,75 @@ macro_rules! tree_leaf {
     };
 }
 
+///
+/// Binary tree of boxed nodes, built with the `tree!` macros or deserialized from a level
+/// order array or a string in bracket notation
+///
 pub struct TreeNode<T> {
     pub val: T,
     pub left: Option<Box<TreeNode<T>>>,
     pub right: Option<Box<TreeNode<T>>>,
 }
 
+impl<T> TreeNode<T> {
+    ///
+    /// Builds a tree from its level order representation (as used by LeetCode), where
+    /// `None` marks a missing child. Every value reserves two slots in the following part
+    /// of the array for its children, values that exceed the slots are ignored.
+    ///
+    /// ## Returns
+    /// `None` if the array is empty or starts with `None`
+    pub fn from_level_order(a: &[Option<T>]) -> Option<Self>
+    where
+        T: Clone,
+    {
+        Self::try_from(Tree::from_level_order(a)).ok()
+    }
+
+    ///
+    /// Level order representation of the tree, the inverse of `from_level_order`
+    pub fn to_level_order(&self) -> Vec<Option<T>>
+    where
+        T: Clone,
+    {
+        binary_tree::to_level_order(self)
+    }
+
+    ///
+    /// Parses a tree in bracket notation, e.g. `1(2(4)(5))(3()(6))`: a node is its value
+    /// followed by the subtrees of its children between parentheses, a missing left child
+    /// is `()` and a missing right child is omitted. Whitespace between tokens is ignored.
+    ///
+    /// ## Errors
+    /// If the string is empty, the parentheses are unbalanced, a node has more than two
+    /// children or a value can't be parsed
+    pub fn from_brackets(s: &str) -> Result<Self, &'static str>
+    where
+        T: FromStr,
+    {
+        Self::try_from(Tree::from_brackets(s)?)
+    }
+
+    ///
+    /// The tree in bracket notation, the inverse of `from_brackets`
+    pub fn to_brackets(&self) -> String
+    where
+        T: Display,
+    {
+        binary_tree::to_brackets(self)
+    }
+
+    ///
+    /// The tree in the Graphviz DOT language, highlighting the nodes in `path` and the edges
+    /// between consecutive ones (see `binary_tree::to_dot`)
+    pub fn to_dot(&self, path: &[&TreeNode<T>]) -> String
+    where
+        T: Display,
+    {
+        binary_tree::to_dot(self, path)
+    }
+}
+
 impl<T> TreeNode<T>
 where
     T: std::fmt::Debug + std::fmt::Display,
@@ -100,49 +168,27 @@ where
     pub fn is_leaf(&self) -> bool {
         matches!((self.get_left(), self.get_right()), (None, None))
     }
+}
 
-    //
-    // String representation of a tree
-    //
-    // Disabled so it doesn't generate clippy warnings [not used]
-    //
-    // pub fn to_string(&self) -> String {
-    //     fn build(
-    //         node: &TreeNode<impl std::fmt::Debug>,
-    //         prefix: String,
-    //         is_left: bool,
-    //         output: &mut String,
-    //         has_sibling: bool,
-    //     ) {
-    //         if let Some(ref right) = node.right {
-    //             let new_prefix = format!("{}{}", prefix, if is_left { "│   " } else { "    " });
-    //             build(right, new_prefix, false, output, node.left.is_some());
-    //         }
-
-    //         output.push_str(&prefix);
-    //         if is_left {
-    //             output.push_str("└── ");
-    //         } else {
-    //             output.push_str("┌── ");
-    //         }
-    //         output.push_str(&format!("{:?}\n", node.val));
-
-    //         if let Some(ref left) = node.left {
-    //             let new_prefix = format!(
-    //                 "{}{}",
-    //                 prefix,
-    //                 if is_left && has_sibling {
-    //                     "│   "
-    //                 } else {
-    //                     "    "
-    //                 }
-    //             );
-    //             build(left, new_prefix, true, output, false);
-    //         }
-    //     }
-
-    //     let mut result = String::new();
-    //     build(self, "".to_string(), false, &mut result, false);
-    //     result
-    // }
+///
+/// Drops the subtrees with an explicit stack: the default drop recurses on every level and
+/// overflows the stack on deep trees
+impl<T> Drop for TreeNode<T> {
+    fn drop(&mut self) {
+        let mut stack: Vec<Box<TreeNode<T>>> = vec![];
+        stack.extend(self.left.take());
+        stack.extend(self.right.take());
+        while let Some(mut node) = stack.pop() {
+            stack.extend(node.left.take());
+            stack.extend(node.right.take());
+        }
+    }
+}
+
+impl<T: Display> Display for TreeNode<T> {
+    ///
+    /// Draws the tree sideways with box-drawing characters, see `binary_tree::render`
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        binary_tree::render(self, f)
+    }
 }
@@ -0,0 +1,203 @@
+/// Sentinel for a child whose subsequence is empty
+const NONE: usize = usize::MAX;
+
+///
+/// Node of a `WaveletTree` covering the ranks `[lo,hi]`: `pref[i]` is how many of the first
+/// `i` items of its subsequence have a rank `<= mid` (so they go to the left child).
+/// Leaves (`lo == hi`) don't need `pref`.
+struct Node {
+    pref: Vec<usize>,
+    left: usize,
+    right: usize,
+}
+
+///
+/// ## Wavelet Tree
+///
+/// Static structure over a sequence of `n` values (`σ` of them distinct) that answers:
+/// - `rank(v, i)`: occurrences of `v` in `a[0..i]`
+/// - `kth_smallest(l, r, k)`: the `k`-th smallest value of `a[l..=r]`
+/// - `count_less(l, r, x)`: how many values of `a[l..=r]` are `< x`
+/// - `range_freq(l, r, lo, hi)`: how many values of `a[l..=r]` fall in `[lo, hi]`
+///
+/// ### Strategy
+/// Values are compressed to their rank among the distinct values. The root covers the
+/// ranks `[0, σ - 1]` and splits the sequence, keeping the order, in the items with a rank
+/// in the lower half (left child) and in the upper half (right child), recursively.
+///
+/// Every node stores, for each prefix of its subsequence, how many items went left. This is
+/// enough to map a range of positions of a node to the ranges of positions of its children,
+/// so every query is a single descent from the root.
+///
+/// ### Complexity
+/// The tree has `O(log(σ))` levels and every level stores `O(n)` counts: `O(nlog(σ))` space
+/// and build time, `O(log(σ))` per query (plus `O(log(σ))` to compress the query values).
+///
+pub struct WaveletTree<T> {
+    values: Vec<T>,
+    nodes: Vec<Node>,
+    len: usize,
+}
+
+impl<T: Ord + Copy> WaveletTree<T> {
+    pub fn new(a: &[T]) -> Self {
+        let mut values = a.to_vec();
+        values.sort_unstable();
+        values.dedup();
+
+        let ranks: Vec<usize> = a.iter().map(|v| values.binary_search(v).unwrap()).collect();
+        let mut t = Self {
+            nodes: vec![],
+            len: a.len(),
+            values,
+        };
+        if !ranks.is_empty() {
+            t.build(&ranks, 0, t.values.len() - 1);
+        }
+        t
+    }
+
+    /// Builds the subtree of `seq` (with ranks in `[lo,hi]`), returns its root
+    fn build(&mut self, seq: &[usize], lo: usize, hi: usize) -> usize {
+        if seq.is_empty() {
+            return NONE;
+        }
+        let idx = self.nodes.len();
+        self.nodes.push(Node {
+            pref: vec![],
+            left: NONE,
+            right: NONE,
+        });
+        if lo == hi {
+            return idx; //leaf
+        }
+
+        let mid = (lo + hi) / 2;
+        let mut pref = Vec::with_capacity(seq.len() + 1);
+        pref.push(0);
+        let (mut left, mut right) = (vec![], vec![]);
+        for &rank in seq {
+            if rank <= mid {
+                left.push(rank);
+            } else {
+                right.push(rank);
+            }
+            pref.push(left.len());
+        }
+        let l = self.build(&left, lo, mid);
+        let r = self.build(&right, mid + 1, hi);
+        self.nodes[idx] = Node {
+            pref,
+            left: l,
+            right: r,
+        };
+        idx
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn check_range(&self, l: usize, r: usize) {
+        if l > r || r >= self.len {
+            panic!("Range not supported")
+        }
+    }
+
+    ///
+    /// Items of `a[l..r]` (half open) whose rank is `< rank`
+    fn count_ranks_below(&self, l: usize, r: usize, rank: usize) -> usize {
+        let (mut idx, mut l, mut r) = (0, l, r);
+        let (mut lo, mut hi) = (0, self.values.len() - 1);
+        let mut res = 0;
+        while l < r && rank > lo {
+            if rank > hi {
+                return res + r - l; //the whole node is below
+            }
+            let node = &self.nodes[idx];
+            let mid = (lo + hi) / 2;
+            let (pl, pr) = (node.pref[l], node.pref[r]);
+            if rank > mid {
+                //the left child is entirely below, keep looking on the right
+                res += pr - pl;
+                (idx, l, r, lo) = (node.right, l - pl, r - pr, mid + 1);
+            } else {
+                (idx, l, r, hi) = (node.left, pl, pr, mid);
+            }
+        }
+        res
+    }
+
+    /* PUBLIC API */
+    /// Occurrences of `v` in `a[0..i]` (`i` excluded)
+    pub fn rank(&self, v: T, i: usize) -> usize {
+        if i > self.len {
+            panic!("Index out of bounds")
+        }
+        let Ok(rank) = self.values.binary_search(&v) else {
+            return 0;
+        };
+        let (mut idx, mut i) = (0, i);
+        let (mut lo, mut hi) = (0, self.values.len() - 1);
+        while i > 0 && lo < hi {
+            let node = &self.nodes[idx];
+            let mid = (lo + hi) / 2;
+            if rank <= mid {
+                (idx, i, hi) = (node.left, node.pref[i], mid);
+            } else {
+                (idx, i, lo) = (node.right, i - node.pref[i], mid + 1);
+            }
+        }
+        i
+    }
+
+    /* PUBLIC API */
+    ///
+    /// The `k`-th smallest value (0-based, `k = 0` is the minimum) in `a[l..=r]`
+    ///
+    /// ## Returns
+    /// `None` if the range holds `k` values or less
+    pub fn kth_smallest(&self, l: usize, r: usize, k: usize) -> Option<T> {
+        self.check_range(l, r);
+        if k > r - l {
+            return None;
+        }
+        let (mut idx, mut l, mut r, mut k) = (0, l, r + 1, k);
+        let (mut lo, mut hi) = (0, self.values.len() - 1);
+        while lo < hi {
+            let node = &self.nodes[idx];
+            let mid = (lo + hi) / 2;
+            let (pl, pr) = (node.pref[l], node.pref[r]);
+            if k < pr - pl {
+                (idx, l, r, hi) = (node.left, pl, pr, mid);
+            } else {
+                k -= pr - pl;
+                (idx, l, r, lo) = (node.right, l - pl, r - pr, mid + 1);
+            }
+        }
+        Some(self.values[lo])
+    }
+
+    /* PUBLIC API */
+    /// How many values of `a[l..=r]` are `< x`
+    pub fn count_less(&self, l: usize, r: usize, x: T) -> usize {
+        self.check_range(l, r);
+        self.count_ranks_below(l, r + 1, self.values.partition_point(|v| *v < x))
+    }
+
+    /* PUBLIC API */
+    /// How many values of `a[l..=r]` fall in `[lo, hi]`
+    pub fn range_freq(&self, l: usize, r: usize, lo: T, hi: T) -> usize {
+        self.check_range(l, r);
+        if lo > hi {
+            return 0;
+        }
+        let below_hi = self.values.partition_point(|v| *v <= hi);
+        let below_lo = self.values.partition_point(|v| *v < lo);
+        self.count_ranks_below(l, r + 1, below_hi) - self.count_ranks_below(l, r + 1, below_lo)
+    }
+}
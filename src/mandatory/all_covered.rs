@@ -79,7 +79,7 @@ pub fn all_covered_sweep(intervals: &[(i32, i32)], int: (i32, i32)) -> bool {
     let (start, end) = (int.0, int.1);
     let mut pairs: Vec<(i32, Event)> = intervals
         .iter()
-        .flat_map(|&(s, e)| ([(s, Event::Begin), (e, Event::End)]))
+        .flat_map(|&(s, e)| [(s, Event::Begin), (e, Event::End)])
         .collect();
 
     pairs.sort_unstable();
@@ -129,16 +129,16 @@ mod test_all_covered {
     fn test_1() {
         let v = [(1, 2), (3, 4), (5, 6)];
         let int = (2, 5);
-        assert_eq!(all_covered(&v, int), true);
-        assert_eq!(all_covered_sweep(&v, int), true);
+        assert!(all_covered(&v, int));
+        assert!(all_covered_sweep(&v, int));
     }
 
     #[test]
     fn test_2() {
         let v = [(1, 10), (10, 20)];
         let int = (21, 21);
-        assert_eq!(all_covered(&v, int), false);
-        assert_eq!(all_covered_sweep(&v, int), false);
+        assert!(!all_covered(&v, int));
+        assert!(!all_covered_sweep(&v, int));
     }
 
     #[test]
@@ -154,7 +154,7 @@ mod test_all_covered {
             (28, 39),
         ];
         let int = (2, 15);
-        assert_eq!(all_covered(&v, int), true);
-        assert_eq!(all_covered_sweep(&v, int), true);
+        assert!(all_covered(&v, int));
+        assert!(all_covered_sweep(&v, int));
     }
 }
@@ -44,10 +44,10 @@ pub fn continous_subarray_sum(nums: &[i64], k: i64) -> bool {
 
         let modulo = sum % k;
 
-        if let Some(prev_index) = map.get(&(modulo)) {
-            if i as i32 - prev_index >= 2 {
-                return true;
-            }
+        if let Some(prev_index) = map.get(&(modulo))
+            && i as i32 - prev_index >= 2
+        {
+            return true;
         }
 
         map.insert(modulo, i as i32);
@@ -62,15 +62,15 @@ mod test_continous_subarray_sum {
     #[test]
     fn test_false() {
         for i in -5..5 {
-            assert_eq!(continous_subarray_sum(&[], i), false);
-            assert_eq!(continous_subarray_sum(&[i], i), false);
+            assert!(!continous_subarray_sum(&[], i));
+            assert!(!continous_subarray_sum(&[i], i));
         }
     }
 
     #[test]
     fn test_some() {
-        assert_eq!(continous_subarray_sum(&[23, 2, 4, 6, 7], 6), true);
-        assert_eq!(continous_subarray_sum(&[23, 2, 6, 4, 7], 6), true);
-        assert_eq!(continous_subarray_sum(&[23, 2, 6, 4, 7], 13), false);
+        assert!(continous_subarray_sum(&[23, 2, 4, 6, 7], 6));
+        assert!(continous_subarray_sum(&[23, 2, 6, 4, 7], 6));
+        assert!(!continous_subarray_sum(&[23, 2, 6, 4, 7], 13));
     }
 }
@@ -1,3 +1,4 @@
+use crate::data_structs::binary_tree;
 use crate::data_structs::tree::TreeNode;
 
 ///
@@ -12,67 +13,60 @@ use crate::data_structs::tree::TreeNode;
 ///
 /// ## Details
 /// This solution is implemented for a binary tree implemented as `TreeNode`, found in
-/// `crate_base_dir/data_structs`
+/// `crate_base_dir/data_structs`. The algorithm itself is `binary_tree::max_path_sum`, which
+/// runs on any `BinaryTree` (e.g. the arena based `hands_on_1::Tree`)
 ///
 /// ## Strategy
 ///
 /// By definition of the problem every leaf is a special node, or the root of the tree
 /// can be a special node if it only has one child
 ///
-/// The idea is to recursively traverse the tree, and for each subtree return a tuple of 2 elements:
+/// The idea is to traverse the tree bottom-up, and for each subtree return a tuple of 2 elements:
 /// - `max_path`: max path from a leaf to the root of the subtree
 /// - `max_path_sum`: the max_path_sum of the subtree
 ///
 /// Follows that if a node is a leaf, then the max_path is its value while the max_path_sum doesn't exist
 ///
-/// When processing a subtree, we call the traversal on both childrens and we return the score of the
+/// When processing a subtree, we take the results of both childrens and we return the score of the
 /// subtree as follows:
 /// - `max_path`: `max(max_path_left,max_path_right) + root`
 /// - `max_path`: can be updated if a subtree has exactely 2 children (if not it inherits the sum of the only child),
 ///   it gets updated if only if `max_path_left + max_path_right + root` is greather than both max_paths sum
 ///
-/// We handle the possiblity of having a special node in the root, calling explicitly the traversal on the subtrees
-/// and using `i32::MIN` as "discard" values for max operations
+/// We handle the possiblity of having a special node in the root, checking it after the traversal
+/// and using `None` as the max_path_sum of a subtree with a single leaf
+///
+/// The traversal is `binary_tree::fold_postorder`: nodes are visited in postorder with an explicit
+/// stack, and the results of the subtrees wait on a second stack until their parent takes them.
+/// There is no recursion, so deep trees don't overflow the call stack
 ///
 /// ## Complexity
 /// We need to traverse the whole tree to compute the path-sum of 2 arbitrary special
 /// nodes, this requires O(n) linear time.
 ///
-/// No additional space is required
+/// The two stacks hold O(h) entries, which is O(n) auxiliary space in the worst case
+/// (a tree degenerated to a list)
 ///
 pub fn max_path_sum(t: &TreeNode<i32>) -> Option<i32> {
-    match (t.get_left(), t.get_right()) {
-        (None, None) => None,
-        (Some(t0), None) | (None, Some(t0)) => {
-            //consider paths from root to leaf
-            let (path, sum) = max_path_rec(t0);
-            Some(sum.max(path + t.val))
-        }
-        (Some(t0), Some(t1)) => {
-            let (path_l, sum_l) = max_path_rec(t0);
-            let (path_r, sum_r) = max_path_rec(t1);
-            Some(sum_l.max(sum_r).max(t.val + path_l + path_r))
-        }
-    }
+    binary_tree::max_path_sum(t)
 }
 
-/*
- * Internal helper function
- */
-fn max_path_rec(t: &TreeNode<i32>) -> (i32, i32) {
-    match (t.get_left(), t.get_right()) {
-        (None, None) => (t.val, i32::MIN),
-        (Some(t0), None) | (None, Some(t0)) => {
-            let (path, sum) = max_path_rec(t0);
-            (t.val + path, sum)
-        }
-        (Some(t0), Some(t1)) => {
-            let (path_l, sum_l) = max_path_rec(t0);
-            let (path_r, sum_r) = max_path_rec(t1);
-            let sum = path_l + path_r + t.val;
-            (path_l.max(path_r) + t.val, sum.max(sum_l).max(sum_r))
-        }
-    }
+///
+/// # Max Path Sum With Path
+///
+/// Same as `max_path_sum`, also returning the values of the nodes on the path that achieves
+/// the max sum, from one special node to the other
+///
+/// ## Details
+/// The path is the one of `binary_tree::max_path_sum_with_path`: it starts from the left subtree
+/// of its highest node, and ties are broken deterministically (the path whose highest node comes
+/// first in postorder, going down to the left child when both children lead to the same sum).
+/// To highlight it with `TreeNode::to_dot`, pass it the nodes returned by
+/// `binary_tree::max_path_sum_with_path` instead of their values
+///
+pub fn max_path_sum_with_path(t: &TreeNode<i32>) -> Option<(i32, Vec<i32>)> {
+    binary_tree::max_path_sum_with_path(t)
+        .map(|(sum, path)| (sum, path.iter().map(|n| n.val).collect()))
 }
 
 #[cfg(test)]
@@ -126,4 +120,15 @@ mod test_max_path_sum {
         let expected = Some(3);
         assert_eq!(max_path_sum(&t), expected);
     }
+
+    #[test]
+    fn test_with_path() {
+        assert_eq!(max_path_sum_with_path(&tree_leaf!(10)), None);
+
+        let t = tree!(3, tree!(4, tree_leaf!(-10), tree_leaf!(4)), tree_leaf!(5));
+        assert_eq!(max_path_sum_with_path(&t), Some((16, vec![4, 4, 3, 5])));
+
+        let t = tree_right!(1, tree_left!(2, tree_leaf!(3)));
+        assert_eq!(max_path_sum_with_path(&t), Some((6, vec![1, 2, 3])));
+    }
 }
@@ -1,4 +1,7 @@
-use crate::data_structs::segment_tree::SegmentTreeSum;
+use crate::data_structs::dynamic_segment_tree::DynamicSegmentTree;
+use crate::data_structs::merge_sort_tree::MergeSortTree;
+use crate::data_structs::monoid::Sum;
+use crate::data_structs::segment_tree::SegmentTree;
 
 ///# Nested Segments
 ///
@@ -12,18 +15,17 @@ use crate::data_structs::segment_tree::SegmentTreeSum;
 /// for the i'th segment we have to count the number of segments j such that l_i < l_j
 /// and r_i > r_j.
 ///
-/// We initialize the segment tree with a number of cells equal to max.r - min.r then we
-/// sort the segments by l. For each r in the segment we add 1 to the tree to record the
-/// end of the segment.
+/// We initialize a sum segment tree with a number of cells equal to max.r - min.r, where
+/// each cell counts the segments ending at that r. Then we sort the segments by l.
 ///
-/// When processing each segment, we query range_sum (r-1) to get the number of segments, then
-/// we add -1 to `r` to not account for the current segment when processing the next segment
+/// When processing each segment, we query the sum on `[0,r-1]` to get the number of segments, then
+/// we decrement the count at `r` to not account for the current segment when processing the next segment
 ///
 /// ## Complexity
 /// We sort the semgment, then for each segment we perform segment tree operations so the
 /// runtime is O(nlog(n))
 ///
-/// We require O(n) space to store the fenwick tree
+/// We require O(n) space to store the segment tree
 ///
 pub fn nested_segments(segs: &[(i32, i32)]) -> Vec<usize> {
     let mut res = vec![0; segs.len()];
@@ -41,13 +43,13 @@ pub fn nested_segments(segs: &[(i32, i32)]) -> Vec<usize> {
 
     // 2. Size of segment tree based on compressed r-coordinates
     let size = (max_r - min_r + 1) as usize;
-    let mut st: SegmentTreeSum<i32> = SegmentTreeSum::build(&vec![0; size]);
 
-    // 3. Add 1 at each segment's right endpoint
+    // 3. Count 1 at each segment's right endpoint, then build the tree on the counts
+    let mut ends = vec![0; size];
     for &(_, r) in segs {
-        let index = (r - min_r) as usize;
-        st.range_add(index, index, 1); // mark where segments end
+        ends[(r - min_r) as usize] += 1; // mark where segments end
     }
+    let mut st: SegmentTree<Sum<i32>> = SegmentTree::build(&ends);
 
     // 4. Sort by left endpoint (increasing), ties broken by r (doesn't matter since no overlap)
     let mut segs_sorted: Vec<(i32, i32, usize)> = segs
@@ -61,19 +63,109 @@ pub fn nested_segments(segs: &[(i32, i32)]) -> Vec<usize> {
     for (_, r, i) in segs_sorted {
         let index = (r - min_r) as usize;
         if index > 0 {
-            res[i] = st.range_sum(0, index - 1) as usize;
+            res[i] = st.query(0, index - 1) as usize;
         } else {
             res[i] = 0;
         }
 
         // Remove this segment’s right endpoint so it doesn’t affect future counts
-        st.range_add(index, index, -1);
+        st.set(index, st.get(index) - 1);
     }
 
     res
 }
 
 
+///# Nested Segments (sparse coordinates)
+///
+/// Same problem and strategy of `nested_segments`, but the counts of the right endpoints
+/// are stored in a `DynamicSegmentTree` over `[min.r, max.r]`.
+///
+/// `nested_segments` allocates a cell for every coordinate in `[min.r, max.r]`, which doesn't
+/// fit in memory when the endpoints are spread (e.g. `[-10^9, 10^9]`). The dynamic tree only
+/// allocates the nodes on the paths to the `n` endpoints.
+///
+/// ## Complexity
+/// Given `C = max.r - min.r + 1`, every segment costs a query and 2 updates on a tree of
+/// height `log(C)`, so the runtime is O(nlog(n) + nlog(C)) (sorting included)
+///
+/// We require O(nlog(C)) space to store the tree
+///
+pub fn nested_segments_dynamic(segs: &[(i32, i32)]) -> Vec<usize> {
+    let mut res = vec![0; segs.len()];
+
+    if segs.is_empty() {
+        return res;
+    }
+
+    // 1. The key range of the tree is [min_r, max_r], no normalization needed
+    let min_r = segs.iter().map(|&(_, r)| r as i64).min().unwrap();
+    let max_r = segs.iter().map(|&(_, r)| r as i64).max().unwrap();
+    let mut st: DynamicSegmentTree<Sum<i32>> = DynamicSegmentTree::new(min_r, max_r);
+
+    // 2. Count 1 at each segment's right endpoint
+    for &(_, r) in segs {
+        st.set(r as i64, st.get(r as i64) + 1);
+    }
+
+    // 3. Sort by left endpoint (increasing)
+    let mut segs_sorted: Vec<(i32, i32, usize)> = segs
+        .iter()
+        .enumerate()
+        .map(|(i, &(l, r))| (l, r, i))
+        .collect();
+    segs_sorted.sort_by_key(|&(l, _, _)| l);
+
+    // 4. For each segment, count segments that end before it (r_j < r_i), then remove it
+    for (_, r, i) in segs_sorted {
+        let r = r as i64;
+        res[i] = st.query(min_r, r - 1) as usize;
+        st.set(r, st.get(r) - 1);
+    }
+
+    res
+}
+
+///# Nested Segments (merge sort tree)
+///
+/// Same problem of `nested_segments`, answered with independent range queries instead of
+/// a sweep that removes the processed segments.
+///
+/// Once the segments are sorted by left endpoint, the segments contained in the one at position
+/// `p` are the ones after `p` (greater left endpoint) with a smaller right endpoint: a count of
+/// values `< r_p` in the range `[p + 1, n - 1]` of the right endpoints, which a `MergeSortTree`
+/// answers online.
+///
+/// ## Complexity
+/// Sorting and building the tree take O(nlog(n)), then every query takes O(log(n))
+///
+/// We require O(nlog(n)) space to store the tree
+///
+pub fn nested_segments_merge_sort_tree(segs: &[(i32, i32)]) -> Vec<usize> {
+    let mut res = vec![0; segs.len()];
+
+    // 1. Sort by left endpoint (increasing)
+    let mut segs_sorted: Vec<(i32, i32, usize)> = segs
+        .iter()
+        .enumerate()
+        .map(|(i, &(l, r))| (l, r, i))
+        .collect();
+    segs_sorted.sort_by_key(|&(l, _, _)| l);
+
+    // 2. Build the tree on the right endpoints, in the sorted order
+    let ends: Vec<i32> = segs_sorted.iter().map(|&(_, r, _)| r).collect();
+    let mst = MergeSortTree::new(&ends);
+
+    // 3. For each segment, count the following segments that end before it
+    for (p, &(_, r, i)) in segs_sorted.iter().enumerate() {
+        if p + 1 < ends.len() {
+            res[i] = mst.count_less(p + 1, ends.len() - 1, r);
+        }
+    }
+
+    res
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -83,6 +175,8 @@ mod tests {
         let segs: Vec<(i32, i32)> = vec![];
         let expected: Vec<usize> = vec![];
         assert_eq!(nested_segments(&segs), expected);
+        assert_eq!(nested_segments_dynamic(&segs), expected);
+        assert_eq!(nested_segments_merge_sort_tree(&segs), expected);
     }
 
     #[test]
@@ -90,6 +184,8 @@ mod tests {
         let segs = vec![(1, 5)];
         let expected = vec![0];
         assert_eq!(nested_segments(&segs), expected);
+        assert_eq!(nested_segments_dynamic(&segs), expected);
+        assert_eq!(nested_segments_merge_sort_tree(&segs), expected);
     }
 
     #[test]
@@ -97,6 +193,8 @@ mod tests {
         let segs = vec![(1, 3), (4, 6)];
         let expected = vec![0, 0];
         assert_eq!(nested_segments(&segs), expected);
+        assert_eq!(nested_segments_dynamic(&segs), expected);
+        assert_eq!(nested_segments_merge_sort_tree(&segs), expected);
     }
 
     #[test]
@@ -104,6 +202,8 @@ mod tests {
         let segs = vec![(1, 10), (3, 7)];
         let expected = vec![1, 0]; // First contains second
         assert_eq!(nested_segments(&segs), expected);
+        assert_eq!(nested_segments_dynamic(&segs), expected);
+        assert_eq!(nested_segments_merge_sort_tree(&segs), expected);
     }
 
     #[test]
@@ -111,6 +211,8 @@ mod tests {
         let segs = vec![(1, 10), (2, 9), (3, 8), (4, 7)];
         let expected = vec![3, 2, 1, 0];
         assert_eq!(nested_segments(&segs), expected);
+        assert_eq!(nested_segments_dynamic(&segs), expected);
+        assert_eq!(nested_segments_merge_sort_tree(&segs), expected);
     }
 
     #[test]
@@ -118,6 +220,8 @@ mod tests {
         let segs = vec![(4, 7), (3, 8), (2, 9), (1, 10)];
         let expected = vec![0, 1, 2, 3]; // Order doesn't affect correctness
         assert_eq!(nested_segments(&segs), expected);
+        assert_eq!(nested_segments_dynamic(&segs), expected);
+        assert_eq!(nested_segments_merge_sort_tree(&segs), expected);
     }
 
     #[test]
@@ -126,6 +230,8 @@ mod tests {
         // Index 0 contains 2, index 3 contains 2
         let expected = vec![1, 0, 0, 1];
         assert_eq!(nested_segments(&segs), expected);
+        assert_eq!(nested_segments_dynamic(&segs), expected);
+        assert_eq!(nested_segments_merge_sort_tree(&segs), expected);
     }
 
     #[test]
@@ -133,6 +239,21 @@ mod tests {
         let segs = vec![(1, 5), (3, 7), (2, 6)];
         let expected = vec![0, 0, 0]; // No segment fully contains another
         assert_eq!(nested_segments(&segs), expected);
+        assert_eq!(nested_segments_dynamic(&segs), expected);
+        assert_eq!(nested_segments_merge_sort_tree(&segs), expected);
+    }
+
+    #[test]
+    fn test_huge_coordinates() {
+        let segs = vec![
+            (-1_000_000_000, 1_000_000_000),
+            (-5, 999_999_999),
+            (0, 7),
+            (i32::MIN, i32::MAX),
+        ];
+        let expected = vec![2, 1, 0, 3];
+        assert_eq!(nested_segments_dynamic(&segs), expected);
+        assert_eq!(nested_segments_merge_sort_tree(&segs), expected);
     }
 
 }
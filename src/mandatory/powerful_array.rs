@@ -1,9 +1,63 @@
-use std::cmp::Ordering::*;
+use crate::data_structs::mo::{self, MoState, MoUpdateState};
 use std::collections::HashMap;
-pub struct Query {
-    left: usize,
-    right: usize,
-    idx: usize,
+
+/*
+ * We update power, using a delta:
+ *
+ * After an insertion we have
+ * ((c+1)^2 * x) - (c^2 * x) = (2c + 1) * x
+ *
+ */
+/// Frequency map and power of a range, updated one item at a time
+#[derive(Default)]
+struct Counter {
+    frequency: HashMap<i64, i64>,
+    power: i64,
+}
+
+impl Counter {
+    fn add(&mut self, x: i64) {
+        let entry = self.frequency.entry(x).or_insert(0);
+        self.power += (2 * *entry + 1) * x;
+        *entry += 1;
+    }
+
+    fn remove(&mut self, x: i64) {
+        let entry = self.frequency.get_mut(&x).unwrap(); // guaranteed present
+        self.power -= (2 * *entry - 1) * x;
+        *entry -= 1;
+    }
+}
+
+/// Frequency map and power of the current range of a `power_array` query
+pub struct Power<'a> {
+    a: &'a [i64],
+    counter: Counter,
+}
+
+impl<'a> Power<'a> {
+    pub fn new(a: &'a [i64]) -> Self {
+        Self {
+            a,
+            counter: Counter::default(),
+        }
+    }
+}
+
+impl MoState for Power<'_> {
+    type Answer = i64;
+
+    fn add(&mut self, i: usize) {
+        self.counter.add(self.a[i]);
+    }
+
+    fn remove(&mut self, i: usize) {
+        self.counter.remove(self.a[i]);
+    }
+
+    fn answer(&self) -> i64 {
+        self.counter.power
+    }
 }
 
 /// # Power Array
@@ -30,9 +84,9 @@ pub struct Query {
 /// the new query range. So we evict items that do not appear in this range and add the new ones. If ranges overlap
 /// this is a neat optimization.
 ///
-/// Since the amortized complexity is strictly related to the order of queries, we cna reorder them using Mo's technique,
-/// so store them in sqrt(n) buckets based on their left range point, and sort the buckets by right range points. We also
-/// have to store the index to compose them back.
+/// Since the amortized complexity is strictly related to the order of queries, we reorder them using Mo's technique
+/// (see `data_structs::mo`): the `Power` state only knows how to add or remove an item from the current range,
+/// while the shared driver sorts the queries and moves the range ends.
 ///
 /// ## Complexity
 /// Since the left pointer can move at most sqrt(n) positions and the left pointer at most n positions the asymptotic complexity
@@ -41,76 +95,88 @@ pub struct Query {
 /// The space complexity is O(n)
 ///
 pub fn powerful_array(a: &[i64], q: &[(usize, usize)]) -> Vec<i64> {
-    let mut answers = vec![0; q.len()];
-    let mut queries: Vec<Query> = q
-        .iter()
-        .enumerate()
-        .map(|(idx, &(left, right))| Query { left, right, idx })
-        .collect();
-    let block_size = (a.len() as f64).sqrt() as usize;
-
-    //we sort the queries based on the bucket index (left index/block_size) and right index
-    queries.sort_by(
-        |a, b| match (a.left / block_size).cmp(&((b.left) / block_size)) {
-            Equal => a.right.cmp(&b.right),
-            Less => Less,
-            Greater => Greater,
-        },
-    );
-
-    let mut frequency: HashMap<i64, i64> = HashMap::new();
-    let (mut left, mut right) = (0, 0);
-    let mut power = a[0];
-    frequency.insert(a[0], 1);
-
-    //closures to update power and frequency map
-
-    /*
-     * We update power, using a delta:
-     *
-     * After an insertion we have
-     * ((c+1)^2 * x) - (c^2 * x) = (2c + 1) * x
-     *
-     */
-    fn add(x: i64, frequency: &mut HashMap<i64, i64>, power: &mut i64) {
-        let entry = frequency.entry(x).or_insert(0);
-        let count = *entry;
-        *power += (2 * count + 1) * x;
-        *entry += 1;
+    mo::solve(a.len(), q, &mut Power::new(a))
+}
+
+/// Operation of `powerful_array_with_assignments`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Operation {
+    /// `a[i] = x`
+    Assign(usize, i64),
+    /// `power(l,r)` on the current array
+    Power(usize, usize),
+}
+
+/// `Power` state over an array that receives point assignments
+struct PowerWithAssignments {
+    a: Vec<i64>,
+    //position and value of each assignment, the value is swapped with the array on toggle
+    assignments: Vec<(usize, i64)>,
+    counter: Counter,
+}
+
+impl MoState for PowerWithAssignments {
+    type Answer = i64;
+
+    fn add(&mut self, i: usize) {
+        self.counter.add(self.a[i]);
     }
 
-    fn rem(x: i64, frequency: &mut HashMap<i64, i64>, power: &mut i64) {
-        let entry = frequency.get_mut(&x).unwrap(); // guaranteed present
-        let count = *entry;
-        *power -= (2 * count - 1) * x;
-        *entry -= 1;
+    fn remove(&mut self, i: usize) {
+        self.counter.remove(self.a[i]);
     }
 
-    for q in queries {
-        while left > q.left {
-            left -= 1;
-            add(a[left], &mut frequency, &mut power);
-        }
+    fn answer(&self) -> i64 {
+        self.counter.power
+    }
+}
 
-        while left < q.left {
-            left += 1;
-            rem(a[right], &mut frequency, &mut power);
-        }
+impl MoUpdateState for PowerWithAssignments {
+    fn position(&self, t: usize) -> usize {
+        self.assignments[t].0
+    }
 
-        while right < q.right {
-            right += 1;
-            add(a[right], &mut frequency, &mut power);
-        }
+    fn toggle(&mut self, t: usize) {
+        let (i, x) = &mut self.assignments[t];
+        std::mem::swap(&mut self.a[*i], x);
+    }
+}
 
-        while right > q.right {
-            right -= 1;
-            rem(a[right], &mut frequency, &mut power);
+/// # Power Array with assignments
+///
+/// Same as `powerful_array`, but the `power(l,r)` queries are interleaved with point
+/// assignments `a[i] = x`, and each query is answered on the array as it is at that moment.
+///
+/// ## Returns
+/// a vector with the answer of each `Power` operation, in order
+///
+/// ## Strategy
+/// Every query is tagged with the number of assignments that precede it, then we run Mo's
+/// algorithm with updates (see `data_structs::mo::solve_with_updates`), where an assignment
+/// swaps the value in the array with the one stored in the operation, so that the same swap
+/// also undoes it when moving back in time.
+///
+/// ## Complexity
+/// `O((n + q) n^(2/3))` time with `q` operations, the space complexity is O(n + q)
+///
+pub fn powerful_array_with_assignments(a: &[i64], ops: &[Operation]) -> Vec<i64> {
+    let mut assignments = vec![];
+    let mut queries = vec![];
+    for op in ops {
+        match *op {
+            Operation::Assign(i, x) => assignments.push((i, x)),
+            Operation::Power(l, r) => queries.push((l, r, assignments.len())),
         }
-
-        answers[q.idx] = power;
     }
-
-    answers
+    if assignments.iter().any(|&(i, _)| i >= a.len()) {
+        panic!("Index out of bounds")
+    }
+    let mut state = PowerWithAssignments {
+        a: a.to_vec(),
+        assignments,
+        counter: Counter::default(),
+    };
+    mo::solve_with_updates(a.len(), state.assignments.len(), &queries, &mut state)
 }
 
 #[cfg(test)]
@@ -0,0 +1,83 @@
+use crate::data_structs::mo::{self, MoState};
+
+/// Occurrences of the (compressed) values in the current range of a query
+pub struct Distinct {
+    ranks: Vec<usize>,
+    frequency: Vec<usize>,
+    distinct: usize,
+}
+
+impl Distinct {
+    ///
+    /// Compresses the values to their rank among the distinct values, so that the
+    /// frequency table is a plain vector
+    pub fn new(a: &[i64]) -> Self {
+        let mut values = a.to_vec();
+        values.sort_unstable();
+        values.dedup();
+        Self {
+            ranks: a.iter().map(|v| values.binary_search(v).unwrap()).collect(),
+            frequency: vec![0; values.len()],
+            distinct: 0,
+        }
+    }
+}
+
+impl MoState for Distinct {
+    type Answer = usize;
+
+    fn add(&mut self, i: usize) {
+        let count = &mut self.frequency[self.ranks[i]];
+        if *count == 0 {
+            self.distinct += 1;
+        }
+        *count += 1;
+    }
+
+    fn remove(&mut self, i: usize) {
+        let count = &mut self.frequency[self.ranks[i]];
+        *count -= 1;
+        if *count == 0 {
+            self.distinct -= 1;
+        }
+    }
+
+    fn answer(&self) -> usize {
+        self.distinct
+    }
+}
+
+/// ## Distinct Values
+///
+/// Given a list of integers, answer a set of offline queries `(l,r)`: how many distinct
+/// values appear in `a[l..=r]`.
+///
+/// ### Strategy
+/// We run Mo's algorithm (see `data_structs::mo`) with a frequency table of the values in the
+/// current range: a value becomes distinct when its count goes from 0 to 1, and stops being
+/// distinct when it goes back to 0.
+///
+/// ### Complexity
+/// `O(nlog(n))` to compress the values and `O((n + q) sqrt(n))` to answer the queries.
+///
+/// The space complexity is O(n)
+///
+pub fn distinct_values(a: &[i64], q: &[(usize, usize)]) -> Vec<usize> {
+    mo::solve(a.len(), q, &mut Distinct::new(a))
+}
+
+/// ## K-Good Segments
+///
+/// Given a list of integers and `k`, answer a set of offline queries `(l,r)`: is `a[l..=r]`
+/// a k-good segment, i.e. does it contain at most `k` distinct values?
+///
+/// ### Strategy
+/// A segment is k-good if its count of distinct values is at most `k`, so we reuse the
+/// `Distinct` state of `distinct_values` on the same Mo's driver.
+///
+/// ### Complexity
+/// Same as `distinct_values`
+///
+pub fn k_good_segments(a: &[i64], k: usize, q: &[(usize, usize)]) -> Vec<bool> {
+    distinct_values(a, q).into_iter().map(|d| d <= k).collect()
+}
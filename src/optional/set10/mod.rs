@@ -1 +1,2 @@
+pub mod distinct_values;
 pub mod power_array;
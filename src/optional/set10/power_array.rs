@@ -1,10 +1,7 @@
-use std::cmp::Ordering::*;
-use std::collections::HashMap;
-pub struct Query {
-    left: usize,
-    right: usize,
-    idx: usize,
-}
+use crate::data_structs::mo;
+
+/// The state of the range is shared with the mandatory solution
+pub use crate::mandatory::powerful_array::Power;
 
 /// ## Power Array
 ///
@@ -30,9 +27,9 @@ pub struct Query {
 /// the new query range. So we evict items that do not appear in this range and add the new ones. If ranges overlap
 /// this is a neat optimization.
 ///
-/// Since the amortized complexity is strictly related to the order of queries, we cna reorder them using Mo's technique,
-/// so store them in sqrt(n) buckets based on their left range point, and sort the buckets by right range points. We also
-/// have to store the index to compose them back.
+/// Since the amortized complexity is strictly related to the order of queries, we reorder them using Mo's technique
+/// (see `data_structs::mo`): the `Power` state only knows how to add or remove an item from the current range,
+/// while the shared driver sorts the queries and moves the range ends.
 ///
 /// ### Complexity
 /// Since the left pointer can move at most sqrt(n) positions and the left pointer at most n positions the asymptotic complexity
@@ -41,74 +38,5 @@ pub struct Query {
 /// The space complexity is O(n)
 ///
 pub fn power_array(a: &[i64], q: &[(usize, usize)]) -> Vec<i64> {
-    let mut answers = vec![0; q.len()];
-    let mut queries: Vec<Query> = q
-        .iter()
-        .enumerate()
-        .map(|(idx, &(left, right))| Query { left, right, idx })
-        .collect();
-    let block_size = (a.len() as f64).sqrt() as usize;
-
-    //we sort the queries based on the bucket index (left index/block_size) and right index
-    queries.sort_by(
-        |a, b| match (a.left / block_size).cmp(&((b.left) / block_size)) {
-            Equal => a.right.cmp(&b.right),
-            Less => Less,
-            Greater => Greater,
-        },
-    );
-
-    let mut frequency: HashMap<i64, i64> = HashMap::new();
-    let (mut left, mut right) = (0, 0);
-    let mut power = a[0];
-    frequency.insert(a[0], 1);
-
-    //closures to update power and frequency map
-
-    /*
-     * We update power, using a delta:
-     *
-     * After an insertion we have
-     * ((c+1)^2 * x) - (c^2 * x) = (2c + 1) * x
-     *
-     */
-    fn add(x: i64, frequency: &mut HashMap<i64, i64>, power: &mut i64) {
-        let entry = frequency.entry(x).or_insert(0);
-        let count = *entry;
-        *power += (2 * count + 1) * x;
-        *entry += 1;
-    }
-
-    fn rem(x: i64, frequency: &mut HashMap<i64, i64>, power: &mut i64) {
-        let entry = frequency.get_mut(&x).unwrap(); // guaranteed present
-        let count = *entry;
-        *power -= (2 * count - 1) * x;
-        *entry -= 1;
-    }
-
-    for q in queries {
-        while left > q.left {
-            left -= 1;
-            add(a[left], &mut frequency, &mut power);
-        }
-
-        while left < q.left {
-            left += 1;
-            rem(a[right], &mut frequency, &mut power);
-        }
-
-        while right < q.right {
-            right += 1;
-            add(a[right], &mut frequency, &mut power);
-        }
-
-        while right > q.right {
-            right -= 1;
-            rem(a[right], &mut frequency, &mut power);
-        }
-
-        answers[q.idx] = power;
-    }
-
-    answers
+    mo::solve(a.len(), q, &mut Power::new(a))
 }
@@ -90,16 +90,16 @@ pub fn job_sequencing_heap(jobs: &[(usize, usize)]) -> (usize, usize) {
     let mut heap: BinaryHeap<Rev<(usize, usize)>> = BinaryHeap::new(); // (deadline, profit)
 
     let mut sorted_jobs = jobs.to_vec();
-    sorted_jobs.sort_by(|a, b| a.0.cmp(&b.0)); // Sort by deadline ascending
+    sorted_jobs.sort_by_key(|a| a.0); // Sort by deadline ascending
 
     for &(deadline, profit) in &sorted_jobs {
         if heap.len() < deadline {
             heap.push(Rev((deadline, profit)));
-        } else if let Some(&Rev((_, min_profit))) = heap.peek() {
-            if profit > min_profit {
-                heap.pop();
-                heap.push(Rev((deadline, profit)));
-            }
+        } else if let Some(&Rev((_, min_profit))) = heap.peek()
+            && profit > min_profit
+        {
+            heap.pop();
+            heap.push(Rev((deadline, profit)));
         }
     }
 
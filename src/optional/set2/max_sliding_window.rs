@@ -1,5 +1,9 @@
 #![allow(unused)]
-use std::collections::{BTreeSet, BinaryHeap, VecDeque};
+use crate::data_structs::monoid::Max;
+use crate::data_structs::monotonic::sliding_max;
+use crate::data_structs::sliding_window::fold_windows;
+use crate::data_structs::sparse_table::SparseTable;
+use std::collections::{BTreeSet, BinaryHeap};
 
 /// **MAX SLIDING WINDOW (bruteforce)**
 ///
@@ -241,75 +245,87 @@ pub fn max_sliding_window_heap(nums: &[i32], k: usize) -> Option<Vec<i32>> {
 /// * `win(2) = [5,3,4]`, max = 3
 /// * `win(3) = [3,4,3]`, max = 4
 ///
-/// We use a Deque data structure that allows for O(1) complexity for insertion at the top and back of
-/// the queue
+/// We use a `MonotonicQueue`, a Deque that allows for O(1) insertion and removal at both ends
 ///
-/// *Process First Window*
-///
-/// We push each element of the first window, while checking if the back of the queue contains values
-/// that are less than it (if so we remove them).
+/// When an item is pushed, all the items at the back that are less or equal than it are
+/// evicted: they leave the window before it, so they can't be the max anymore.
+/// The items in the deque are decreasing, and the max of the window is at the front.
 ///
-/// *Process other windows*
-/// While inserting items (from the second to the last window), we peek into the heap, removing
-/// all max values that are not in our current window
+/// When the window slides, the oldest item is removed from the front (if it wasn't
+/// already evicted)
 ///
-/// *Space Complexity*:     O(n)
-///
-/// *Time Complexity*:      O(n * log(n))
+/// *Space Complexity*:     O(k)
 ///
-/// Complexity is worse than the TreeSet case since we could accumulate items
-/// that we don't need (other windows) in the heap
+/// *Time Complexity*:      O(n)
 ///
+/// Every item is pushed and evicted at most once
 ///
 pub fn max_sliding_window_deque(nums: &[i32], k: usize) -> Option<Vec<i32>> {
-    let ln = nums.len();
-    if ln < k || k == 0 {
+    if nums.len() < k || k == 0 {
         return None;
     }
 
-    let windows = (ln - k) + 1;
-
-    //alloc for max elements
-    let mut maxs: Vec<i32> = Vec::with_capacity(windows);
-    //deque for indexes
-    let mut d = VecDeque::<usize>::new();
+    Some(sliding_max(nums.iter().copied(), k).collect())
+}
 
-    //process the first window
-    //evict all elements (from the back) that are less than the current value
-    for (i, &n) in nums.iter().enumerate().take(k) {
-        while let Some(&idx) = d.back() {
-            if n > nums[idx] {
-                d.pop_back();
-            } else {
-                break;
-            }
-        }
-        d.push_back(i);
+/// **MAX SLIDING WINDOW (Sparse Table)**
+///
+/// Given a vector of integers (`i32`) and an unsigned integer, return
+/// for all windows of size k the max value
+///
+/// **Example**
+/// * `a = [7,2,5,3,4,3]`
+/// * `k = 3`
+///
+/// * `win(0) = [7,2,5]`, max = 7
+/// * `win(1) = [2,5,3]`, max = 5
+/// * `win(2) = [5,3,4]`, max = 5
+/// * `win(3) = [3,4,3]`, max = 4
+///
+/// Max is idempotent, so we build a `SparseTable` on the vector and every window
+/// is a constant time range query. It pays off when many window sizes are queried on the
+/// same vector, since the table doesn't depend on `k`.
+///
+/// *Space Complexity*:     O(n * log(n))
+///
+/// *Time Complexity*:      O(n * log(n))
+///
+pub fn max_sliding_window_sparse_table(nums: &[i32], k: usize) -> Option<Vec<i32>> {
+    let ln = nums.len();
+    if ln < k || k == 0 {
+        return None;
     }
 
-    //push max of the current window
-    maxs.push(nums[*d.front().unwrap()]);
-
-    for (i, &n) in nums.iter().enumerate().skip(k) {
-        while let Some(&idx) = d.front() {
-            if idx <= i - k {
-                //index of the current window
-                d.pop_front();
-            } else {
-                break;
-            }
-        }
-
-        //evict all items that are less than teh current
-        while let Some(&idx) = d.back()
-            && n > nums[idx]
-        {
-            d.pop_back();
-        }
+    let st = SparseTable::new(nums, |a: &i32, b: &i32| *a.max(b));
+    Some((0..=ln - k).map(|i| st.query(i, i + k - 1)).collect())
+}
 
-        d.push_back(i);
-        maxs.push(nums[*d.front().unwrap()]);
+/// **MAX SLIDING WINDOW (Two Stacks Queue)**
+///
+/// Given a vector of integers (`i32`) and an unsigned integer, return
+/// for all windows of size k the max value
+///
+/// **Example**
+/// * `a = [7,2,5,3,4,3]`
+/// * `k = 3`
+///
+/// * `win(0) = [7,2,5]`, max = 7
+/// * `win(1) = [2,5,3]`, max = 5
+/// * `win(2) = [5,3,4]`, max = 5
+/// * `win(3) = [3,4,3]`, max = 4
+///
+/// The window is a `SlidingWindow` over the `Max` monoid: a queue made of two stacks that
+/// keep partial aggregates. It doesn't rely on any property of max beside associativity, so
+/// the same code works for sums, gcds or matrix products, and on a stream of values.
+///
+/// *Space Complexity*:     O(k)
+///
+/// *Time Complexity*:      O(n)
+///
+pub fn max_sliding_window_two_stacks(nums: &[i32], k: usize) -> Option<Vec<i32>> {
+    if nums.len() < k || k == 0 {
+        return None;
     }
 
-    Some(maxs)
+    Some(fold_windows::<Max<i32>, _>(nums.iter().copied(), k).collect())
 }
@@ -1,3 +1,4 @@
 pub mod max_sliding_window;
 pub mod next_larger_element;
+pub mod sliding_window_median;
 pub mod trap_water;
@@ -1,3 +1,5 @@
+use crate::data_structs::monotonic::next_greater;
+
 ///
 /// **NEXT LARGER ELEMENT**
 ///
@@ -10,7 +12,7 @@
 /// - Given     `[7,2,5,3,4]`
 /// - Returns   `[-1,5,-1,4,-1]`
 ///
-/// The idea is to use a Stack data structure (we can use a simple vector, with `push/pop`)
+/// The idea is to use a Stack data structure (a `MonotonicStack`)
 /// and iterate from right to left.
 ///
 /// For every element, we evict from the stack all elements that are less or equal
@@ -26,25 +28,10 @@ pub fn next_larger_element(nums: &[u32]) -> Option<Vec<i32>> {
         return None;
     }
 
-    let mut result: Vec<i32> = vec![-1; nums.len()];
-    let mut stack = Vec::<u32>::new();
-
-    for (i, &v) in nums.iter().enumerate().rev() {
-        //evict all elements that are less than this one
-        while let Some(&x) = stack.last() {
-            if x <= v {
-                stack.pop();
-            } else {
-                break;
-            }
-        }
-
-        if let Some(&x) = stack.last() {
-            result[i] = x as i32;
-        }
-
-        stack.push(v);
-    }
+    let result = next_greater(nums)
+        .into_iter()
+        .map(|next| next.map_or(-1, |j| nums[j] as i32))
+        .collect();
 
     Some(result)
 }
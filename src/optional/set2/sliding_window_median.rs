@@ -0,0 +1,46 @@
+use crate::data_structs::order_statistic_tree::OrderStatisticTree;
+
+/// **SLIDING WINDOW MEDIAN (Order Statistic Tree)**
+///
+/// Given a vector of integers (`i32`) and an unsigned integer, return
+/// for all windows of size k the median value (the lower one if k is even)
+///
+/// **Example**
+/// * `a = [7,2,5,3,4,3]`
+/// * `k = 3`
+///
+/// * `win(0) = [7,2,5]`, median = 5
+/// * `win(1) = [2,5,3]`, median = 3
+/// * `win(2) = [5,3,4]`, median = 4
+/// * `win(3) = [3,4,3]`, median = 3
+///
+/// We keep the current window in an `OrderStatisticTree` of tuples `(value, index)`, so
+/// that duplicated values are distinct keys. Sliding the window is a removal and an
+/// insertion, and the median is the `(k-1)/2`-th smallest key, found with `select`.
+///
+/// *Space Complexity*:     O(k)
+///
+/// *Time Complexity*:      O(n * log(k))
+///
+pub fn sliding_window_median(nums: &[i32], k: usize) -> Option<Vec<i32>> {
+    let ln = nums.len();
+    if ln < k || k == 0 {
+        return None;
+    }
+
+    let mut medians: Vec<i32> = Vec::with_capacity(ln - k + 1);
+    let mut tree = OrderStatisticTree::new();
+
+    for (i, &n) in nums.iter().enumerate() {
+        tree.insert((n, i));
+        if i >= k {
+            //evict the item that left the window
+            tree.remove(&(nums[i - k], i - k));
+        }
+        if i + 1 >= k {
+            medians.push(tree.select((k - 1) / 2).unwrap().0);
+        }
+    }
+
+    Some(medians)
+}
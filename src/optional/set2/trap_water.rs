@@ -1,4 +1,5 @@
 #![allow(unused)]
+use crate::data_structs::monotonic::MonotonicStack;
 
 /// **TRAP WATER (2 passes)**
 ///
@@ -126,3 +127,47 @@ pub fn trap_water(heights: &[u32]) -> u32 {
 
     water
 }
+
+/// **TRAP WATER (Monotonic Stack)**
+///
+/// Given an array of positive integers `u32` that represent heights find the max amount
+/// of water that can be trapped between all heights
+///
+/// **EXAMPLE**
+/// ```text
+/// Height: [0,1,0,2,1,0,1,3,2,1,2,1]
+///
+///             X
+///       X w w X X w X
+///   X w X w X X X X X X
+///  XXXXXXXXXXXXXXXXXXXX
+/// ```
+///
+///
+/// Instead of counting the water column by column we fill it layer by layer, using a
+/// strictly decreasing `MonotonicStack` of heights.
+///
+/// A height evicts all the lower (or equal) heights on top of the stack. Every evicted height
+/// is the bottom of a basin, bounded on the left by the height below it in the stack and on
+/// the right by the current one: the basin is filled up to the lowest of the two bounds.
+///
+/// *Time Complexity*: O(n)
+///
+/// *Space Complexity*: O(n)
+pub fn trap_water_stack(heights: &[u32]) -> u32 {
+    let mut stack = MonotonicStack::max();
+    let mut water = 0;
+
+    for (i, &h) in heights.iter().enumerate() {
+        while let Some((_, bottom)) = stack.pop_dominated(&h) {
+            //no left bound, the water would flow away
+            if let Some((left, &left_h)) = stack.top() {
+                let width = (i - left - 1) as u32;
+                water += (left_h.min(h) - bottom) * width;
+            }
+        }
+        stack.push(h);
+    }
+
+    water
+}
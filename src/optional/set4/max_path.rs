@@ -1,3 +1,4 @@
+use crate::data_structs::binary_tree;
 use crate::data_structs::tree::TreeNode;
 
 ///
@@ -10,56 +11,40 @@ use crate::data_structs::tree::TreeNode;
 /// By definition of the problem every leaf is a special node, or the root of the tree
 /// can be a special node if it only has one child
 ///
-/// The idea is to recursively traverse the tree, and for each subtree return a tuple of 2 elements:
+/// The idea is to traverse the tree bottom-up, and for each subtree return a tuple of 2 elements:
 /// - `max_path`: max path from a leaf to the root of the subtree
 /// - `max_path_sum`: the max_path_sum of the subtree
 ///
 /// Follows that if a node is a leaf, then the max_path is its value while the max_path_sum doesn't exist
 ///
-/// When processing a subtree, we call the traversal on both childrens and we return the score of the
+/// When processing a subtree, we take the results of both childrens and we return the score of the
 /// subtree as follows:
 /// - `max_path`: `max(max_path_left,max_path_right) + root`
 /// - `max_path`: can be updated if a subtree has exactely 2 children (if not it inherits the sum of the only child),
 ///   it gets updated if only if `max_path_left + max_path_right + root` is greather than both max_paths sum
 ///
-/// We handle the possiblity of having a special node in the root, calling explicitly the traversal on the subtrees
-/// and using `i32::MIN` as "discard" values for max operations
+/// We handle the possiblity of having a special node in the root, checking it after the traversal
+/// and using `None` as the max_path_sum of a subtree with a single leaf
+///
+/// The traversal is `binary_tree::max_path_sum`, which visits the tree in postorder with an explicit
+/// stack so deep trees don't overflow the call stack
 ///
 /// Returns an `Option<i32>` that is `None` if the tree has only one node
 ///
 /// *Time Complexity*: tree traversal O(n)
 ///
-/// *Space Complexity*: O(1)
+/// *Space Complexity*: O(h) for the stack of the traversal
 ///
 pub fn max_path(t: &TreeNode<i32>) -> Option<i32> {
-    //Tuple having (max_path in subtree , sum_special_nodes in subtree)
-    fn max_path_rec(t: &TreeNode<i32>) -> (i32, i32) {
-        match (t.get_left(), t.get_right()) {
-            (None, None) => (t.val, i32::MIN),
-            (Some(t0), None) | (None, Some(t0)) => {
-                let (path, sum) = max_path_rec(t0);
-                (t.val + path, sum)
-            }
-            (Some(t0), Some(t1)) => {
-                let (path_l, sum_l) = max_path_rec(t0);
-                let (path_r, sum_r) = max_path_rec(t1);
-                let sum = path_l + path_r + t.val;
-                (path_l.max(path_r) + t.val, sum.max(sum_l).max(sum_r))
-            }
-        }
-    }
+    binary_tree::max_path_sum(t)
+}
 
-    match (t.get_left(), t.get_right()) {
-        (None, None) => None,
-        (Some(t0), None) | (None, Some(t0)) => {
-            //consider paths from root to leaf
-            let (path, sum) = max_path_rec(t0);
-            Some(sum.max(path + t.val))
-        }
-        (Some(t0), Some(t1)) => {
-            let (path_l, sum_l) = max_path_rec(t0);
-            let (path_r, sum_r) = max_path_rec(t1);
-            Some(sum_l.max(sum_r).max(t.val + path_l + path_r))
-        }
-    }
+///
+/// Same as `max_path`, also returning the values of the nodes on the path that achieves the
+/// max sum, from one special node to the other (see `binary_tree::max_path_sum_with_path`
+/// for the order of the nodes and how ties are broken)
+///
+pub fn max_path_with_path(t: &TreeNode<i32>) -> Option<(i32, Vec<i32>)> {
+    binary_tree::max_path_sum_with_path(t)
+        .map(|(sum, path)| (sum, path.iter().map(|n| n.val).collect()))
 }
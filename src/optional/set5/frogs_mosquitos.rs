@@ -82,56 +82,55 @@ pub fn frog_mosquitos(frogs: &[(i32, i32)], mosquitos: &[(i32, i32)]) -> Vec<(us
         // Search frog that can eat
         // We want the frog such that (pos + tongue) >= mosquito.pos
         // Range returns an iterator, so we check if we have at least one frog
-        if let Some(&(reach, pos_f, id)) = frog_set.range((pos_m, i32::MIN, usize::MIN)..).next() {
-            if pos_f <= pos_m {
-                //println!("Frog {id} can eat mosquito at position {pos_m}");
-                //remove old entry
-                frog_set.remove(&(reach, pos_f, id));
-
-                //get frog fields from the lookup table
-                let (start, tongue, eaten) = &mut lookup[id];
-
-                //update frog attributes based on this mosquito
-                *eaten += 1;
-                *tongue += size;
-                let mut new_reach = *start + *tongue + size;
-                //println!("Frog {id} reach upgraded from {reach} to {new_reach}");
-
-                //update frog state and keep checking if the new frog can eat pending mosquitos
-                loop {
-                    //we look for all mosquitos which position is lower or equal than our new reach
-
-                    // We could iterate over all mosquitos in the range but we'd have to remove them
-                    // and borrow-checker woudn't allow to perform mutable operations when an immutable
-                    // iterator exists so the complexity is the same
-                    if let Some(&(pos, size)) =
-                        pending_mosquitos.range(..=(new_reach, i32::MAX)).next()
-                    {
-                        //frog can eat this so evict from set
-                        if pos_f <= pos {
-                            //println!("[Pending] Frog {id} can eat mosquito at position {pos}");
-
-                            //evict the mosquito from the set
-                            pending_mosquitos.remove(&(pos, size));
-
-                            //update the frog state
-                            *tongue += size;
-                            *eaten += 1;
-                            let _reach = new_reach; //for debug purposes
-                            new_reach = *start + *tongue;
-
-                            //println!("[Pending] Frog {id} reach upgraded from {_reach} to {new_reach}");
-                            continue;
-                        }
+        if let Some(&(reach, pos_f, id)) = frog_set.range((pos_m, i32::MIN, usize::MIN)..).next()
+            && pos_f <= pos_m
+        {
+            //println!("Frog {id} can eat mosquito at position {pos_m}");
+            //remove old entry
+            frog_set.remove(&(reach, pos_f, id));
+
+            //get frog fields from the lookup table
+            let (start, tongue, eaten) = &mut lookup[id];
+
+            //update frog attributes based on this mosquito
+            *eaten += 1;
+            *tongue += size;
+            let mut new_reach = *start + *tongue + size;
+            //println!("Frog {id} reach upgraded from {reach} to {new_reach}");
+
+            //update frog state and keep checking if the new frog can eat pending mosquitos
+            loop {
+                //we look for all mosquitos which position is lower or equal than our new reach
+
+                // We could iterate over all mosquitos in the range but we'd have to remove them
+                // and borrow-checker woudn't allow to perform mutable operations when an immutable
+                // iterator exists so the complexity is the same
+                if let Some(&(pos, size)) = pending_mosquitos.range(..=(new_reach, i32::MAX)).next()
+                {
+                    //frog can eat this so evict from set
+                    if pos_f <= pos {
+                        //println!("[Pending] Frog {id} can eat mosquito at position {pos}");
+
+                        //evict the mosquito from the set
+                        pending_mosquitos.remove(&(pos, size));
+
+                        //update the frog state
+                        *tongue += size;
+                        *eaten += 1;
+                        let _reach = new_reach; //for debug purposes
+                        new_reach = *start + *tongue;
+
+                        //println!("[Pending] Frog {id} reach upgraded from {_reach} to {new_reach}");
+                        continue;
                     }
-
-                    break;
                 }
 
-                //store the frog back
-                frog_set.insert((new_reach, *start, id));
-                continue; // the frog ate at least a mosquito so we don't insert it in pending
+                break;
             }
+
+            //store the frog back
+            frog_set.insert((new_reach, *start, id));
+            continue; // the frog ate at least a mosquito so we don't insert it in pending
         }
 
         //println!("Inserting Mosquito as pending ({pos_m},{size})");
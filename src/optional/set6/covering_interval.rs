@@ -77,7 +77,7 @@ pub fn covering_intervals_sweep(intervals: &[(i32, i32)], int: (i32, i32)) -> bo
     let (start, end) = (int.0, int.1);
     let mut pairs: Vec<(i32, Event)> = intervals
         .iter()
-        .flat_map(|&(s, e)| ([(s, Event::Begin), (e, Event::End)]))
+        .flat_map(|&(s, e)| [(s, Event::Begin), (e, Event::End)])
         .collect();
 
     pairs.sort_unstable();
@@ -42,10 +42,10 @@ pub fn good_subarray(nums: &[i64], k: i64) -> bool {
 
         let modulo = sum % k;
 
-        if let Some(prev_index) = map.get(&(modulo)) {
-            if i as i32 - prev_index >= 2 {
-                return true;
-            }
+        if let Some(prev_index) = map.get(&(modulo))
+            && i as i32 - prev_index >= 2
+        {
+            return true;
         }
 
         map.insert(modulo, i as i32);
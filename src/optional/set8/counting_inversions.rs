@@ -1,3 +1,7 @@
+use crate::data_structs::dynamic_segment_tree::DynamicSegmentTree;
+use crate::data_structs::fenwick_tree::FenwickTree;
+use crate::data_structs::monoid::Sum;
+
 /// # Counting Inversions
 ///
 /// Given a list of numbers count how many times, given 2 indexes i<j a[i] > a[j]
@@ -72,8 +76,6 @@ pub fn count_inversions_merge(nums: &[i64]) -> usize {
     }
 }
 
-use crate::data_structs::fenwick_tree::FenwickTree;
-
 /// # Count Inversions
 /// Given a list of numbers and 2 indexes such that i<j a[i] > a[j] it's an inversion
 ///
@@ -130,3 +132,44 @@ pub fn count_inversions_fenwick(nums: &[i64]) -> usize {
 
     count
 }
+
+/// # Count Inversions
+/// Given a list of numbers and 2 indexes such that i<j a[i] > a[j] it's an inversion
+///
+/// ## Returns
+/// all inversions in the list
+///
+/// ## Dynamic Segment Tree Approach
+///
+/// Same idea of the Fenwick Tree approach: we count the appearances of the integers seen so
+/// far, and for every integer we accumulate the count of the greater ones.
+///
+/// The Fenwick Tree allocates a cell for every integer in `[min,max]`, which exhausts memory
+/// when values are spread (e.g. `[-10^9, 10^9]`). A `DynamicSegmentTree` over the same key
+/// range only allocates the nodes on the paths to the integers we actually insert.
+///
+/// ## Complexity
+/// ### Time Complexity
+/// Every integer costs a query and an update on a tree of height `log(C)` (where C is
+/// list.max - list.min + 1), so we have O(nlog(C))
+///
+/// ### Space Complexity
+/// Each update allocates at most log(C) nodes, so we result in O(nlog(C))
+///
+pub fn count_inversions_dynamic(nums: &[i64]) -> usize {
+    if nums.is_empty() {
+        return 0;
+    }
+    let (min, max) = (*nums.iter().min().unwrap(), *nums.iter().max().unwrap());
+    let mut st: DynamicSegmentTree<Sum<usize>> = DynamicSegmentTree::new(min, max);
+
+    let mut count = 0;
+    for &e in nums {
+        //count all the bigger integers that came before
+        if e < max {
+            count += st.query(e + 1, max);
+        }
+        st.set(e, st.get(e) + 1);
+    }
+    count
+}
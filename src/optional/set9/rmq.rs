@@ -1,3 +1,4 @@
+use crate::data_structs::monoid::Monoid;
 use crate::data_structs::segment_tree::SegmentTree;
 
 /// ## RMQ
@@ -20,57 +21,53 @@ use crate::data_structs::segment_tree::SegmentTree;
 /// A vector of integers, one for each `rmq` query while performing the `inc` operations
 ///
 /// ### Strategy
-/// Since the array can be large (at most 200000 entries) we can use a segment tree
-/// that supports lazy propagation. The propagation doesn't impact asymptotic complexity
-/// at the worst case but can be a neat optimization that consents to compute on demand
-/// updates on the tree when we're operating on partial overlapping intervals.
+/// Since the array can be large (at most 200000 entries) we need both operations in `O(log(n))`.
+/// We achieve this with a segment tree that only supports point updates, by storing the
+/// difference array of the ring instead of the ring itself.
 ///
 /// Since the segments are handled circularly we have to correctly handle 2 kinds
 /// of intervals:
 /// - [l,r] where l <= r
 /// - [l,r] where l > r
 ///
-/// The first interval can be handled internally by the segment tree implementation.
-/// For the second we can apply an interval decomposition where we consider:
+/// The first interval can be handled directly. For the second we can apply an interval
+/// decomposition where we consider:
 /// - [l,r] (where l > r) as [l,n-1] u [0,r] (inclusive)
 ///
 /// Splitting these we can feed the query operations to the segment tree and compose the
 /// result in the case of `rmq`. For add we just feed the decomposed interval since we don't
 /// have to return any result.
 ///
-/// #### Segment Tree Implementation
-/// The problem requires us handling ranges of integers, so we can come up with a SegmentTree<i64>.
-/// i32 would have served us good, since the range of representability includes the max and min elements (10^6)
-/// but we choose i64 for compatibility and wider range.
+/// #### Difference array
+/// Given the ring `a` we store `d[0] = a[0]` and `d[i] = a[i] - a[i-1]`, so that every
+/// `a[i]` is the prefix sum `d[0] + ... + d[i]`. An `inc(l,r,v)` on a non circular range
+/// only changes 2 cells of `d`: `d[l] += v` and `d[r+1] -= v` (the second is skipped when
+/// `r = n-1`). So range updates on `a` become point updates on `d`.
 ///
-/// To implement the lazy propagation we need double the memory required for the tree which normally would be 2n (so 4n)
-/// because we need an additional tree where we store updates that will be performed later on. The additional tree (`lazy` from now on)
-/// initially stores 0 values.
-/// ##### Building the Tree
-/// We build the tree using a recursive method, an iterative approach is possible but for small trees it's preferrable to do it like so
+/// #### Prefix Min Monoid
+/// A `min` on `a[l..=r]` is a min over prefix sums of `d`. We make the segment tree aggregate
+/// each range of `d` as a pair `(sum, min_prefix)` where `min_prefix` is the minimum among the
+/// prefix sums of the range. Two adjacent ranges combine as:
+/// - `sum = sum_left + sum_right`
+/// - `min_prefix = min(min_prefix_left, sum_left + min_prefix_right)`
 ///
-/// #### Handling the queries
-/// For both queries, we provide an initial range (base of the recursion) as well as the range on where we want to perform the query.
+/// which is associative, with identity `(0, +inf)` (so it is a `Monoid`, albeit non commutative).
 ///
-/// Since the operations we need to provide are 2 we can safely say that by structure:
-/// - the segment tree will store the array elements as leaves, and partial min query results on the internal nodes
-/// - the lazy tree will store values that still need to be added onto subtrees (we will add them when we need consistency for the first query)
-///
-/// Both operations are recursive and we handle them considering the range (for the recursion) and the range of the query.
-/// We consider 3 scenarios:
-/// - no overlap: the range of the query doesn't overlap with the range of the recursion (for `inc` we return, and for `min` we return `i64::MAX`)
-/// - total overlap: the range of the query is fully included by the range of the recursion (we return the current node: can be leaf or intermediate)
-/// - partial overlap: we have to recursively consider both subtrees: (we need constency) so all lazy updates have to be committed before this. Then we
-///   call the recursion on the subtrees (in the case of `min` query we then compose the result as `min(rec_left,rec_right)`)
-/// #### Setting lazy propagation
-/// For the add query we can store intermediate results (in the case of total overlap) in the lazy tree.
-///
-/// If a subsequent `min` query needs a result of a range that wasn't committed yet, then calls `push` that checks the lazy tree
-/// and "push" the updated value to the lazy node children as well as the tree children. With this we guarantee that (at least for the
-/// first recursive call) the intermediate query is consistent. Since every recursive call can call `push` this is an invariant of the
-/// data structure that guarantees consistency on every level of recursion
+/// Then `min(a[l..=r]) = sum(d[0..l]) + min_prefix(d[l..=r])`, which costs 2 range queries.
 pub fn rmq(ring: &[i64], query: &[(usize, usize, Option<i64>)]) -> Vec<i64> {
-    let mut tree = SegmentTree::build(ring);
+    let n = ring.len();
+    let diff: Vec<(i64, i64)> = (0..n)
+        .map(|i| {
+            if i == 0 {
+                ring[0]
+            } else {
+                ring[i] - ring[i - 1]
+            }
+        })
+        .map(|d| (d, d))
+        .collect();
+    let mut tree: SegmentTree<PrefixMin> = SegmentTree::build(&diff);
+
     let mut min_queries = 0;
     for &(_, _, q) in query {
         if q.is_none() {
@@ -85,10 +82,49 @@ pub fn rmq(ring: &[i64], query: &[(usize, usize, Option<i64>)]) -> Vec<i64> {
 
     for &(l, r, q) in query {
         if let Some(v) = q {
-            tree.add_range(l, r, v);
+            if l <= r {
+                add_range(&mut tree, l, r, v);
+            } else {
+                add_range(&mut tree, l, n - 1, v);
+                add_range(&mut tree, 0, r, v);
+            }
+        } else if l <= r {
+            res.push(min_range(&tree, l, r));
         } else {
-            res.push(tree.min_range(l, r));
+            res.push(Ord::min(min_range(&tree, l, n - 1), min_range(&tree, 0, r)));
         }
     }
     res
 }
+
+///
+/// Aggregates a range of the difference array as `(sum, min_prefix)`
+struct PrefixMin;
+
+impl Monoid for PrefixMin {
+    type Value = (i64, i64);
+
+    fn identity() -> (i64, i64) {
+        (0, i64::MAX)
+    }
+
+    fn combine(a: &(i64, i64), b: &(i64, i64)) -> (i64, i64) {
+        (a.0 + b.0, Ord::min(a.1, a.0.saturating_add(b.1)))
+    }
+}
+
+/// Adds `v` to the (non circular) range `[l,r]` of the ring by updating its difference array
+fn add_range(tree: &mut SegmentTree<PrefixMin>, l: usize, r: usize, v: i64) {
+    let (d, _) = tree.get(l);
+    tree.set(l, (d + v, d + v));
+    if r + 1 < tree.len() {
+        let (d, _) = tree.get(r + 1);
+        tree.set(r + 1, (d - v, d - v));
+    }
+}
+
+/// Minimum on the (non circular) range `[l,r]` of the ring
+fn min_range(tree: &SegmentTree<PrefixMin>, l: usize, r: usize) -> i64 {
+    let offset = if l == 0 { 0 } else { tree.query(0, l - 1).0 };
+    offset + tree.query(l, r).1
+}
@@ -0,0 +1,440 @@
+use code::data_structs::binary_tree::{
+    BinaryTree, fold_postorder, is_bst, max_path_sum, max_path_sum_with_path, sum,
+};
+use code::data_structs::tree::TreeNode;
+use code::{tree, tree_leaf, tree_left, tree_right};
+use hands_on_1::Tree;
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use std::collections::HashMap;
+
+/* ---------- helpers ---------- */
+
+///
+/// Random arena tree with `n` nodes: every new node is attached to a free slot of a random
+/// node already in the tree
+fn random_tree(rng: &mut StdRng, n: usize, values: std::ops::Range<i64>) -> Tree<i64> {
+    let mut t = Tree::with_root(rng.random_range(values.clone()));
+    while t.nodes.len() < n {
+        let parent = rng.random_range(0..t.nodes.len());
+        let key = rng.random_range(values.clone());
+        let _ = t.add_node(parent, key, rng.random_bool(0.5));
+    }
+    t
+}
+
+/// Random BST: keys inserted in random order
+fn random_bst(rng: &mut StdRng, n: usize) -> Tree<i64> {
+    let mut t: Tree<i64> = Tree::new();
+    for _ in 0..n {
+        let key = rng.random_range(0..50);
+        if t.nodes.is_empty() {
+            t = Tree::with_root(key);
+            continue;
+        }
+        let mut id = 0;
+        loop {
+            let go_left = key < t.nodes[id].key;
+            let next = if go_left {
+                t.nodes[id].id_left
+            } else {
+                t.nodes[id].id_right
+            };
+            match next {
+                Some(child) => id = child,
+                None => {
+                    t.add_node(id, key, go_left).unwrap();
+                    break;
+                }
+            }
+        }
+    }
+    t
+}
+
+/// Preorder sequence of (value, has left, has right) through the trait
+fn shape<B: BinaryTree>(t: &B) -> Vec<(B::Value, bool, bool)>
+where
+    B::Value: Copy,
+{
+    fn rec<'a, B: BinaryTree>(t: &'a B, id: B::Id<'a>, res: &mut Vec<(B::Value, bool, bool)>)
+    where
+        B::Value: Copy,
+    {
+        let (l, r) = (t.left(id), t.right(id));
+        res.push((*t.value(id), l.is_some(), r.is_some()));
+        for child in [l, r].into_iter().flatten() {
+            rec(t, child, res);
+        }
+    }
+
+    let mut res = vec![];
+    if let Some(root) = t.root() {
+        rec(t, root, &mut res);
+    }
+    res
+}
+
+///
+/// Max path sum by trying every pair of special nodes (degree 1), walking up from both ends
+/// to their lowest common ancestor
+fn naive_max_path_sum(t: &Tree<i64>) -> Option<i64> {
+    let n = t.nodes.len();
+    let mut parent = vec![None; n];
+    let mut depth = vec![0; n];
+    for (id, node) in t.nodes.iter().enumerate() {
+        for child in [node.id_left, node.id_right].into_iter().flatten() {
+            parent[child] = Some(id);
+        }
+    }
+    //children always come after their parent in the arena
+    for id in 1..n {
+        depth[id] = depth[parent[id].unwrap()] + 1;
+    }
+    let degree = |id: usize| {
+        let node = &t.nodes[id];
+        [node.id_left, node.id_right, parent[id]]
+            .iter()
+            .filter(|x| x.is_some())
+            .count()
+    };
+    let special: Vec<usize> = (0..n).filter(|&id| degree(id) == 1).collect();
+
+    let mut best = None;
+    for (i, &a) in special.iter().enumerate() {
+        for &b in &special[i + 1..] {
+            let (mut x, mut y, mut s) = (a, b, 0);
+            while x != y {
+                if depth[x] < depth[y] {
+                    std::mem::swap(&mut x, &mut y);
+                }
+                s += t.nodes[x].key;
+                x = parent[x].unwrap();
+            }
+            s += t.nodes[x].key;
+            best = Some(best.map_or(s, |m: i64| m.max(s)));
+        }
+    }
+    best
+}
+
+///
+/// Degenerate tree of `n` nodes with the values `n..=1` on a left chain, ending with the leaves
+/// `-1` (left) and `0` (right) under the `1`
+fn deep_tree(n: i64) -> TreeNode<i64> {
+    let mut t = tree!(1, tree_leaf!(-1), tree_leaf!(0));
+    for v in 2..=n {
+        t = tree_left!(v, t);
+    }
+    t
+}
+
+///
+/// Checks that `path` (arena ids) is a path of `t` between two special nodes summing to `sum`
+fn check_path(t: &Tree<i64>, sum: i64, path: &[usize]) {
+    let child = |a: usize, b: usize| [t.nodes[a].id_left, t.nodes[a].id_right].contains(&Some(b));
+    let special = |id: usize| {
+        let node = &t.nodes[id];
+        let children = node.id_left.is_some() as usize + node.id_right.is_some() as usize;
+        children + (id != 0) as usize == 1
+    };
+    assert!(path.len() >= 2);
+    assert!(special(path[0]) && special(path[path.len() - 1]));
+    assert!(
+        path.windows(2)
+            .all(|w| child(w[0], w[1]) || child(w[1], w[0]))
+    );
+    assert_eq!(path.iter().map(|&id| t.nodes[id].key).sum::<i64>(), sum);
+}
+
+/// Values visited by a traversal
+fn values<'a, B: BinaryTree>(t: &'a B, ids: impl Iterator<Item = B::Id<'a>>) -> Vec<B::Value>
+where
+    B::Value: Copy,
+{
+    ids.map(|id| *t.value(id)).collect()
+}
+
+/* ---------- deterministic unit tests ---------- */
+
+#[test]
+fn traversal_orders() {
+    //        1
+    //      2   3
+    //     4 5   6
+    //          7
+    let t = tree!(
+        1,
+        tree!(2, tree_leaf!(4), tree_leaf!(5)),
+        tree_right!(3, tree_left!(6, tree_leaf!(7)))
+    );
+    let check = |pre: Vec<i32>, ino: Vec<i32>, post: Vec<i32>, level: Vec<i32>| {
+        assert_eq!(pre, vec![1, 2, 4, 5, 3, 6, 7]);
+        assert_eq!(ino, vec![4, 2, 5, 1, 3, 7, 6]);
+        assert_eq!(post, vec![4, 5, 2, 7, 6, 3, 1]);
+        assert_eq!(level, vec![1, 2, 3, 4, 5, 6, 7]);
+    };
+    check(
+        values(&t, t.preorder()),
+        values(&t, t.inorder()),
+        values(&t, t.postorder()),
+        values(&t, t.level_order()),
+    );
+    let arena = Tree::from(t);
+    check(
+        values(&arena, arena.preorder()),
+        values(&arena, arena.inorder()),
+        values(&arena, arena.postorder()),
+        values(&arena, arena.level_order()),
+    );
+
+    let empty: Tree<i32> = Tree::new();
+    assert_eq!(empty.preorder().count(), 0);
+    assert_eq!(empty.inorder().count(), 0);
+    assert_eq!(empty.postorder().count(), 0);
+    assert_eq!(empty.level_order().count(), 0);
+}
+
+#[test]
+fn fold_postorder_height() {
+    let t = tree!(1, tree_leaf!(2), tree_right!(3, tree_leaf!(4)));
+    let height = fold_postorder(&t, |_, l: Option<usize>, r| {
+        1 + l.unwrap_or(0).max(r.unwrap_or(0))
+    });
+    assert_eq!(height, Some(3));
+    assert_eq!(
+        fold_postorder(&Tree::<i32>::new(), |_, _, _: Option<()>| ()),
+        None
+    );
+}
+
+#[test]
+fn deep_trees() {
+    let n = 1_000_000;
+    let t = deep_tree(n);
+    let expected_sum = n * (n + 1) / 2 - 1;
+    assert_eq!(sum(&t), Some(expected_sum));
+    assert!(!is_bst(&t)); //0 is the right child of 1
+    //from the root (a special node) down to the leaf 0
+    assert_eq!(max_path_sum(&t), Some(expected_sum + 1));
+    assert_eq!(t.inorder().count(), n as usize + 2);
+
+    //the conversion is iterative too
+    let arena = Tree::from(t);
+    assert_eq!(sum(&arena), Some(expected_sum));
+    assert!(!is_bst(&arena));
+    assert_eq!(max_path_sum(&arena), Some(expected_sum + 1));
+    assert_eq!(arena.max_path_sum(), Some(0));
+    assert_eq!(values(&arena, arena.level_order()).last(), Some(&0));
+
+    let t = TreeNode::try_from(arena).unwrap();
+    assert_eq!(t.postorder().count(), n as usize + 2);
+    drop(t); //TreeNode drops its subtrees without recursion
+
+    //a left chain from its level order array: every node but the last has a missing right child
+    let mut a = vec![Some(0)];
+    for i in 1..200_000 {
+        a.extend([Some(i), None]);
+    }
+    let t = TreeNode::from_level_order(&a).unwrap();
+    assert_eq!(t.preorder().count(), 200_000);
+    drop(t);
+}
+
+#[test]
+fn same_results_on_both_representations() {
+    let t = tree!(
+        -15,
+        tree!(5, tree!(-8, tree_leaf!(2), tree_leaf!(-3)), tree_leaf!(1)),
+        tree!(
+            6,
+            tree_leaf!(3),
+            tree_right!(9, tree!(0, tree_leaf!(4), tree_left!(-1, tree_leaf!(10))))
+        )
+    );
+    assert_eq!(max_path_sum(&t), Some(27));
+    assert_eq!(sum(&t), Some(13));
+    assert!(!is_bst(&t));
+
+    let arena = Tree::from(t);
+    assert_eq!(arena.nodes.len(), 13);
+    assert_eq!(max_path_sum(&arena), Some(27));
+    assert_eq!(sum(&arena), Some(13));
+    assert!(!is_bst(&arena));
+}
+
+#[test]
+fn root_with_single_child_is_special() {
+    let t = tree_right!(1, tree_leaf!(2));
+    assert_eq!(max_path_sum(&t), Some(3));
+    assert_eq!(max_path_sum(&Tree::from(t)), Some(3));
+    assert_eq!(max_path_sum(&tree_leaf!(7)), None);
+}
+
+#[test]
+fn empty_arena_tree() {
+    let t: Tree<i32> = Tree::new();
+    assert_eq!(t.root(), None);
+    assert_eq!(sum(&t), None);
+    assert_eq!(max_path_sum(&t), None);
+    assert!(is_bst(&t));
+    assert_eq!(TreeNode::try_from(t).err(), Some("Empty tree"));
+}
+
+#[test]
+fn bst_on_both_representations() {
+    let t = tree!(
+        10,
+        tree!(5, tree_leaf!(3), tree_leaf!(7)),
+        tree_right!(15, tree_leaf!(20))
+    );
+    assert!(is_bst(&t));
+    assert!(is_bst(&Tree::from(t)));
+
+    //12 is in the left subtree of 10
+    let t = tree!(10, tree_right!(5, tree_leaf!(12)), tree_leaf!(15));
+    assert!(!is_bst(&t));
+    assert!(!is_bst(&Tree::from(t)));
+}
+
+#[test]
+fn conversions_are_lossless() {
+    let mut arena = Tree::with_root('m');
+    let a = arena.add_node_left(0, 'c').unwrap();
+    let z = arena.add_node_right(0, 'z').unwrap();
+    arena.add_node_right(a, 'd').unwrap();
+    arena.add_node_left(z, 'x').unwrap();
+    let expected = shape(&arena);
+
+    let boxed = TreeNode::try_from(arena).unwrap();
+    assert_eq!(shape(&boxed), expected);
+    let back = Tree::from(boxed);
+    assert_eq!(shape(&back), expected);
+    //nodes are numbered in preorder
+    let keys: Vec<char> = back.nodes.iter().map(|n| n.key).collect();
+    assert_eq!(keys, vec!['m', 'c', 'd', 'z', 'x']);
+}
+
+#[test]
+fn max_path_with_path() {
+    let t = tree!(
+        -15,
+        tree!(5, tree!(-8, tree_leaf!(2), tree_leaf!(-3)), tree_leaf!(1)),
+        tree!(
+            6,
+            tree_leaf!(3),
+            tree_right!(9, tree!(0, tree_leaf!(4), tree_left!(-1, tree_leaf!(10))))
+        )
+    );
+    let (sum, path) = max_path_sum_with_path(&t).unwrap();
+    let values: Vec<i32> = path.iter().map(|n| n.val).collect();
+    assert_eq!((sum, values), (27, vec![3, 6, 9, 0, -1, 10]));
+    //preorder: -15 5 -8 2 -3 1 6 3 9 0 4 -1 10, the arena is numbered in preorder
+    let arena = Tree::from(t);
+    assert_eq!(
+        max_path_sum_with_path(&arena),
+        Some((27, vec![7, 6, 8, 9, 11, 12]))
+    );
+
+    //root with a single child: from the root down to the leaf
+    let t = Tree::from(tree_right!(1, tree!(2, tree_leaf!(-5), tree_leaf!(-5))));
+    assert_eq!(max_path_sum_with_path(&t), Some((-2, vec![0, 1, 2])));
+    assert!(max_path_sum_with_path(&tree_leaf!(7)).is_none());
+    assert_eq!(max_path_sum_with_path(&Tree::<i32>::new()), None);
+
+    let n = 1_000_000;
+    let t = deep_tree(n);
+    let (sum, path) = max_path_sum_with_path(&t).unwrap();
+    assert_eq!(sum, n * (n + 1) / 2);
+    assert_eq!(path.len(), n as usize + 1);
+}
+
+/* ---------- property‑based randomized test ---------- */
+
+#[test]
+fn random_trees_against_hands_on_1() {
+    let mut rng = StdRng::seed_from_u64(42);
+    for _ in 0..300 {
+        let n = rng.random_range(1..40);
+        let arena = random_tree(&mut rng, n, -20..20);
+        let expected_shape = shape(&arena);
+        let expected_sum = arena.sum();
+        let expected_bst = arena.is_bst();
+        //hands_on_1 only counts leaves as special nodes: same answer when the root is not one
+        let root_special = arena.nodes[0].id_left.is_some() != arena.nodes[0].id_right.is_some();
+        let expected_path = arena.max_path_sum();
+
+        assert_eq!(sum(&arena), expected_sum);
+        assert_eq!(is_bst(&arena), expected_bst);
+        let path = max_path_sum(&arena);
+        if !root_special {
+            assert_eq!(path, expected_path);
+        }
+
+        let boxed = TreeNode::try_from(arena).unwrap();
+        assert_eq!(shape(&boxed), expected_shape);
+        assert_eq!(sum(&boxed), expected_sum);
+        assert_eq!(is_bst(&boxed), expected_bst);
+        assert_eq!(max_path_sum(&boxed), path);
+
+        let back = Tree::from(boxed);
+        assert_eq!(shape(&back), expected_shape);
+    }
+}
+
+#[test]
+fn random_bsts() {
+    let mut rng = StdRng::seed_from_u64(7);
+    for _ in 0..100 {
+        let n = rng.random_range(1..60);
+        let arena = random_bst(&mut rng, n);
+        assert!(is_bst(&arena));
+        assert!(arena.is_bst());
+        assert!(is_bst(&TreeNode::try_from(arena).unwrap()));
+    }
+}
+
+#[test]
+fn random_max_path_against_naive() {
+    let mut rng = StdRng::seed_from_u64(42);
+    for _ in 0..200 {
+        let n = rng.random_range(1..30);
+        let arena = random_tree(&mut rng, n, -100..100);
+        let expected = naive_max_path_sum(&arena);
+        assert_eq!(max_path_sum(&arena), expected, "n={n}");
+        assert_eq!(max_path_sum(&TreeNode::try_from(arena).unwrap()), expected);
+    }
+}
+
+#[test]
+fn random_max_path_with_path() {
+    let mut rng = StdRng::seed_from_u64(7);
+    for _ in 0..300 {
+        let n = rng.random_range(1..30);
+        //few values: many ties
+        let arena = random_tree(&mut rng, n, -3..4);
+        let expected = naive_max_path_sum(&arena);
+        let res = max_path_sum_with_path(&arena);
+        assert_eq!(res.as_ref().map(|(s, _)| *s), expected, "n={n}");
+        if let Some((sum, path)) = &res {
+            check_path(&arena, *sum, path);
+        }
+        //hands_on_1 only counts leaves as special nodes: same path when the root is not one
+        if arena.nodes[0].id_left.is_some() == arena.nodes[0].id_right.is_some() {
+            assert_eq!(arena.max_path_sum_with_path(), res);
+        }
+
+        //same path on the boxed nodes, as preorder positions once converted back to an arena
+        let boxed = TreeNode::try_from(arena).unwrap();
+        let position: HashMap<usize, usize> = boxed
+            .preorder()
+            .enumerate()
+            .map(|(i, node)| (boxed.index(node), i))
+            .collect();
+        let expected = max_path_sum_with_path(&boxed).map(|(sum, path)| {
+            let path: Vec<usize> = path.iter().map(|&n| position[&boxed.index(n)]).collect();
+            (sum, path)
+        });
+        assert_eq!(expected.as_ref().map(|(s, _)| *s), res.map(|(s, _)| s));
+        assert_eq!(max_path_sum_with_path(&Tree::from(boxed)), expected);
+    }
+}
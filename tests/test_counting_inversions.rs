@@ -1,12 +1,14 @@
 #![allow(unused_imports)]
 use code::optional::set8::counting_inversions::{
-    count_inversions_fenwick, count_inversions_merge, count_inversions_naive,
+    count_inversions_dynamic, count_inversions_fenwick, count_inversions_merge,
+    count_inversions_naive,
 };
 use code::test_util::TestCase;
 
 type TestC<'a> = TestCase<&'a [i64], usize>;
 
-const TO_TEST: [fn(&[i64]) -> usize; 3] = [
+const TO_TEST: [fn(&[i64]) -> usize; 4] = [
+    count_inversions_dynamic,
     count_inversions_fenwick,
     count_inversions_merge,
     count_inversions_naive,
@@ -41,3 +43,12 @@ fn test_some() {
     TestC::new(&[3, 5, 1, 10, 9, 2, 6, 8], 11).test_multiple(&TO_TEST);
     TestC::new(&[4, 3, 2, 1, 0, -1], 15).test_multiple(&TO_TEST);
 }
+
+#[test]
+fn test_huge_spread() {
+    let nums = [1_000_000_000, -1_000_000_000, 0, 999_999_999, -999_999_999];
+    let expected = count_inversions_naive(&nums);
+    TestC::new(&nums, expected).test(count_inversions_dynamic);
+    TestC::new(&nums, expected).test(count_inversions_merge);
+    TestC::new(&[i64::MAX, 0, i64::MIN], 3).test(count_inversions_dynamic);
+}
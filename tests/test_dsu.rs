@@ -0,0 +1,143 @@
+use code::data_structs::dsu::{Dsu, RollbackDsu};
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+/* ---------- helpers ---------- */
+
+/// Naive union-find: every element stores the label of its component
+struct Labels(Vec<usize>);
+
+impl Labels {
+    fn union(&mut self, a: usize, b: usize) -> bool {
+        let (la, lb) = (self.0[a], self.0[b]);
+        if la == lb {
+            return false;
+        }
+        self.0
+            .iter_mut()
+            .filter(|l| **l == lb)
+            .for_each(|l| *l = la);
+        true
+    }
+
+    fn size(&self, x: usize) -> usize {
+        self.0.iter().filter(|&&l| l == self.0[x]).count()
+    }
+
+    fn count(&self) -> usize {
+        let mut labels = self.0.clone();
+        labels.sort();
+        labels.dedup();
+        labels.len()
+    }
+
+    fn components(&self) -> Vec<Vec<usize>> {
+        let mut groups: Vec<Vec<usize>> = vec![];
+        for x in 0..self.0.len() {
+            match groups.iter_mut().find(|g| self.0[g[0]] == self.0[x]) {
+                Some(g) => g.push(x),
+                None => groups.push(vec![x]),
+            }
+        }
+        groups
+    }
+}
+
+/* ---------- deterministic unit tests ---------- */
+
+#[test]
+fn union_find_and_sizes() {
+    let mut dsu = Dsu::new(6);
+    assert_eq!(dsu.count(), 6);
+    assert!(dsu.union(0, 1));
+    assert!(dsu.union(4, 5));
+    assert!(dsu.union(1, 5));
+    assert!(!dsu.union(0, 4));
+    assert!(dsu.same(0, 5));
+    assert!(!dsu.same(2, 3));
+    assert_eq!(dsu.size(4), 4);
+    assert_eq!(dsu.size(3), 1);
+    assert_eq!(dsu.count(), 3);
+    let components: Vec<_> = dsu.components().collect();
+    assert_eq!(components, vec![vec![0, 1, 4, 5], vec![2], vec![3]]);
+}
+
+#[test]
+fn empty_dsu() {
+    let mut dsu = Dsu::new(0);
+    assert!(dsu.is_empty());
+    assert_eq!(dsu.components().count(), 0);
+}
+
+#[test]
+fn rollback_restores_components() {
+    let mut dsu = RollbackDsu::new(5);
+    dsu.union(0, 1);
+    let snapshot = dsu.snapshot();
+    assert!(dsu.union(2, 3));
+    assert!(!dsu.union(1, 0));
+    assert!(dsu.union(1, 3));
+    assert_eq!(dsu.size(2), 4);
+    assert_eq!(dsu.count(), 2);
+
+    dsu.rollback(snapshot);
+    assert_eq!(dsu.count(), 4);
+    assert!(dsu.same(0, 1));
+    assert!(!dsu.same(2, 3));
+    assert_eq!(dsu.size(0), 2);
+    let components: Vec<_> = dsu.components().collect();
+    assert_eq!(components, vec![vec![0, 1], vec![2], vec![3], vec![4]]);
+}
+
+#[test]
+#[should_panic(expected = "Snapshot not recorded")]
+fn rollback_to_the_future() {
+    let mut dsu = RollbackDsu::new(2);
+    dsu.rollback(1);
+}
+
+/* ---------- property‑based randomized test ---------- */
+
+#[test]
+fn random_unions_against_naive() {
+    let mut rng = StdRng::seed_from_u64(42);
+    for n in [1, 2, 10, 50] {
+        let mut dsu = Dsu::new(n);
+        let mut naive = Labels((0..n).collect());
+        for _ in 0..200 {
+            let (a, b) = (rng.random_range(0..n), rng.random_range(0..n));
+            assert_eq!(dsu.union(a, b), naive.union(a, b));
+            let x = rng.random_range(0..n);
+            assert_eq!(dsu.size(x), naive.size(x));
+            assert_eq!(dsu.same(a, x), naive.0[a] == naive.0[x]);
+            assert_eq!(dsu.count(), naive.count());
+        }
+        assert_eq!(dsu.components().collect::<Vec<_>>(), naive.components());
+    }
+}
+
+#[test]
+fn random_rollbacks_against_naive() {
+    let mut rng = StdRng::seed_from_u64(7);
+    for n in [1, 2, 10, 50] {
+        let mut dsu = RollbackDsu::new(n);
+        //naive states after each union, to go back to
+        let mut history = vec![Labels((0..n).collect())];
+        for _ in 0..300 {
+            if rng.random_bool(0.2) {
+                let snapshot = rng.random_range(0..history.len());
+                dsu.rollback(snapshot);
+                history.truncate(snapshot + 1);
+            } else {
+                let (a, b) = (rng.random_range(0..n), rng.random_range(0..n));
+                let mut next = Labels(history.last().unwrap().0.clone());
+                assert_eq!(dsu.union(a, b), next.union(a, b));
+                history.push(next);
+            }
+            let naive = history.last().unwrap();
+            let x = rng.random_range(0..n);
+            assert_eq!(dsu.size(x), naive.size(x));
+            assert_eq!(dsu.count(), naive.count());
+            assert_eq!(dsu.components().collect::<Vec<_>>(), naive.components());
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use code::data_structs::dynamic_segment_tree::DynamicSegmentTree;
+use code::data_structs::monoid::{Max, Sum};
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use std::collections::BTreeMap;
+
+/* ---------- deterministic unit tests ---------- */
+
+#[test]
+fn untouched_keys_hold_identity() {
+    let st: DynamicSegmentTree<Sum<i64>> = DynamicSegmentTree::new(-1_000_000_000, 1_000_000_000);
+    assert_eq!(st.query(-1_000_000_000, 1_000_000_000), 0);
+    assert_eq!(st.get(42), 0);
+    assert_eq!(st.nodes(), 1);
+}
+
+#[test]
+fn huge_range_set_and_query() {
+    let mut st: DynamicSegmentTree<Sum<i64>> =
+        DynamicSegmentTree::new(-1_000_000_000, 1_000_000_000);
+    st.set(-1_000_000_000, 3);
+    st.set(1_000_000_000, 4);
+    st.set(0, 5);
+    assert_eq!(st.query(-1_000_000_000, 1_000_000_000), 12);
+    assert_eq!(st.query(-999_999_999, 999_999_999), 5);
+    assert_eq!(st.query(1, 1_000_000_000), 4);
+    assert_eq!(st.query(5, 1), 0); // empty range
+    // every update allocates at most one path of ~31 nodes
+    assert!(st.nodes() <= 1 + 3 * 31);
+}
+
+#[test]
+fn whole_i64_domain() {
+    let mut st: DynamicSegmentTree<Max<i64>> = DynamicSegmentTree::new(i64::MIN, i64::MAX);
+    st.set(i64::MIN, 1);
+    st.set(i64::MAX, 2);
+    st.set(-1, 7);
+    assert_eq!(st.query(i64::MIN, i64::MAX), 7);
+    assert_eq!(st.query(0, i64::MAX), 2);
+    assert_eq!(st.get(i64::MIN), 1);
+}
+
+/* ---------- property‑based randomized test ---------- */
+
+#[test]
+fn random_operations_against_naive() {
+    const OPS: usize = 1000;
+    let (lo, hi) = (-1_000_000_000_000i64, 1_000_000_000_000i64);
+    let mut rng = StdRng::seed_from_u64(42);
+    let mut st: DynamicSegmentTree<Sum<i64>> = DynamicSegmentTree::new(lo, hi);
+    let mut naive: BTreeMap<i64, i64> = BTreeMap::new();
+    // a small pool of keys so that queries hit updated values
+    let keys: Vec<i64> = (0..50).map(|_| rng.random_range(lo..=hi)).collect();
+
+    for _ in 0..OPS {
+        if rng.random_bool(0.5) {
+            let key = keys[rng.random_range(0..keys.len())];
+            let v = rng.random_range(-100..100);
+            st.set(key, v);
+            naive.insert(key, v);
+        } else {
+            let (a, b) = (rng.random_range(lo..=hi), rng.random_range(lo..=hi));
+            let (l, r) = (a.min(b), a.max(b));
+            let expected: i64 = naive.range(l..=r).map(|(_, v)| v).sum();
+            assert_eq!(st.query(l, r), expected);
+        }
+    }
+}
@@ -0,0 +1,183 @@
+use code::data_structs::fenwick_tree::{FenwickTree, RangeUpdate, UpdateArray};
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+/* ---------- helpers ---------- */
+
+fn naive_prefix(data: &[i64], i: usize) -> i64 {
+    data[..=i].iter().sum()
+}
+
+/// First index whose prefix sum reaches `target` (non negative data)
+fn naive_lower_bound(data: &[i64], target: i64) -> Option<usize> {
+    (0..data.len()).find(|&i| naive_prefix(data, i) >= target)
+}
+
+fn expect_tree_matches_array(ft: &FenwickTree<i64>, data: &[i64]) {
+    assert_eq!(ft.iter().collect::<Vec<_>>(), data);
+    for i in 0..data.len() {
+        assert_eq!(ft.access(i), Ok(data[i]), "access({i})");
+        assert_eq!(ft.sum(i), Ok(naive_prefix(data, i)), "sum({i})");
+    }
+}
+
+/* ---------- deterministic unit tests ---------- */
+
+#[test]
+fn from_builds_a_fenwick_tree() {
+    let data = vec![3, 1, 4, 1, 5, 9, 2, 6, 5];
+    let mut ft = FenwickTree::from(&data);
+    assert_eq!(ft.len(), data.len());
+    expect_tree_matches_array(&ft, &data);
+
+    //updates after `from` must see a valid tree
+    ft.add(2, 10).unwrap();
+    assert_eq!(ft.sum(2), Ok(18));
+    assert_eq!(ft.sum(8), Ok(46));
+    assert_eq!(ft.range_sum(3, 5), Ok(15));
+}
+
+#[test]
+fn from_empty_slice() {
+    let ft = FenwickTree::<i64>::from(&[]);
+    assert!(ft.is_empty());
+    assert_eq!(ft.iter().count(), 0);
+    assert_eq!(ft.lower_bound(0), None);
+}
+
+#[test]
+fn set_replaces_values() {
+    let mut ft = FenwickTree::from(&[1, 2, 3, 4]);
+    ft.set(1, 10).unwrap();
+    ft.set(3, -4).unwrap();
+    expect_tree_matches_array(&ft, &[1, 10, 3, -4]);
+    assert!(ft.set(4, 0).is_err());
+}
+
+#[test]
+fn lower_bound_on_counts() {
+    //appearances of the values 0..6 in the multiset {1, 1, 3, 5, 5, 5}
+    let ft = FenwickTree::from(&[0, 2, 0, 1, 0, 3]);
+    let kth: Vec<_> = (0..6).map(|k| ft.lower_bound(k + 1).unwrap()).collect();
+    assert_eq!(kth, vec![1, 1, 3, 5, 5, 5]);
+    assert_eq!(ft.lower_bound(7), None);
+    assert_eq!(ft.lower_bound(0), Some(0));
+}
+
+#[test]
+fn set_decreases_unsigned_counts() {
+    //appearances of the values 0..4 in the multiset {1, 2, 2, 2, 3}
+    let mut ft = FenwickTree::<u32>::from(&[0, 1, 3, 1]);
+    ft.set(2, 1).unwrap(); //{1, 2, 3}
+    assert_eq!(ft.iter().collect::<Vec<_>>(), vec![0, 1, 1, 1]);
+    assert_eq!(
+        (0..4).map(|i| ft.sum(i).unwrap()).collect::<Vec<_>>(),
+        vec![0, 1, 2, 3]
+    );
+    assert_eq!(ft.lower_bound(2), Some(2));
+    assert_eq!(ft.lower_bound(3), Some(3));
+    assert_eq!(ft.lower_bound(4), None);
+
+    let mut ft = FenwickTree::<usize>::from(&[5, 0, 2]);
+    ft.set(0, 0).unwrap();
+    ft.set(2, 3).unwrap();
+    assert_eq!(ft.range_sum(0, 2), Ok(3));
+    assert_eq!(ft.lower_bound(1), Some(2));
+}
+
+/* ---------- property‑based randomized test ---------- */
+
+#[test]
+fn random_operations_against_naive() {
+    let mut rng = StdRng::seed_from_u64(42);
+    for n in [1, 2, 7, 16, 33] {
+        let mut data: Vec<i64> = (0..n).map(|_| rng.random_range(0..10)).collect();
+        let mut ft = FenwickTree::from(&data);
+        for _ in 0..300 {
+            let i = rng.random_range(0..n);
+            match rng.random_range(0..4) {
+                0 => {
+                    let v = rng.random_range(0..10);
+                    ft.add(i, v).unwrap();
+                    data[i] += v;
+                }
+                1 => {
+                    let v = rng.random_range(0..10);
+                    ft.set(i, v).unwrap();
+                    data[i] = v;
+                }
+                2 => {
+                    let target = rng.random_range(0..=data.iter().sum::<i64>() + 1);
+                    assert_eq!(
+                        ft.lower_bound(target),
+                        naive_lower_bound(&data, target),
+                        "lower_bound({target})"
+                    );
+                }
+                _ => {
+                    let j = rng.random_range(i..n);
+                    let expected: i64 = data[i..=j].iter().sum();
+                    assert_eq!(ft.range_sum(i, j), Ok(expected), "range=({i},{j})");
+                }
+            }
+        }
+        expect_tree_matches_array(&ft, &data);
+    }
+}
+
+#[test]
+fn update_array_range_updates() {
+    let mut ua = UpdateArray::with_len(5, 0i64);
+    ua.range_update(1, 3, 2).unwrap();
+    ua.range_update(0, 4, 1).unwrap();
+    ua.add(3, 5).unwrap();
+    let values: Vec<_> = (0..5).map(|i| ua.access(i).unwrap()).collect();
+    assert_eq!(values, vec![1, 3, 3, 8, 1]);
+    assert!(ua.range_update(2, 1, 1).is_err());
+    assert!(ua.range_update(0, 5, 1).is_err());
+}
+
+#[test]
+fn range_update_from_left_end() {
+    let mut ru = RangeUpdate::from(&[1i64, 2, 3, 4]);
+    ru.range_update(0, 1, 10).unwrap(); // [11,12,3,4]
+    assert_eq!(ru.sum(0), Ok(11));
+    assert_eq!(ru.sum(3), Ok(30));
+    assert_eq!(ru.range_sum(1, 2), Ok(15));
+    ru.range_update(0, 3, -1).unwrap(); // [10,11,2,3]
+    assert_eq!(ru.access(0), Ok(10));
+    assert_eq!(ru.range_sum(0, 3), Ok(26));
+}
+
+#[test]
+fn random_range_updates_against_naive() {
+    let mut rng = StdRng::seed_from_u64(7);
+    for n in [1, 2, 5, 16, 31] {
+        let mut data: Vec<i64> = (0..n).map(|_| rng.random_range(-20..20)).collect();
+        let mut ru = RangeUpdate::from(&data);
+        let mut ua = UpdateArray::with_len(n, 0i64);
+        let mut added = vec![0i64; n];
+        for _ in 0..300 {
+            let l = rng.random_range(0..n);
+            let r = rng.random_range(l..n);
+            match rng.random_range(0..3) {
+                0 => {
+                    let v = rng.random_range(-20..20);
+                    ru.range_update(l, r, v).unwrap();
+                    ua.range_update(l, r, v).unwrap();
+                    for i in l..=r {
+                        data[i] += v;
+                        added[i] += v;
+                    }
+                }
+                1 => {
+                    assert_eq!(ru.access(l), Ok(data[l]), "access({l})");
+                    assert_eq!(ua.access(l), Ok(added[l]), "access({l})");
+                }
+                _ => {
+                    let expected: i64 = data[l..=r].iter().sum();
+                    assert_eq!(ru.range_sum(l, r), Ok(expected), "range=({l},{r})");
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,150 @@
+use code::data_structs::fenwick_tree_2d::FenwickTree2D;
+use code::data_structs::prefix_sum_2d::PrefixSum2D;
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+/* ---------- helpers ---------- */
+
+/// Sum of a rectangle of a flattened row-major grid
+fn naive_rect(grid: &[i64], cols: usize, r1: usize, c1: usize, r2: usize, c2: usize) -> i64 {
+    (r1..=r2)
+        .map(|r| grid[r * cols + c1..=r * cols + c2].iter().sum::<i64>())
+        .sum()
+}
+
+/* ---------- deterministic unit tests ---------- */
+
+#[test]
+fn prefix_sum_rectangles() {
+    #[rustfmt::skip]
+    let grid = vec![
+        1, 2, 3,
+        4, 5, 6,
+    ];
+    let ps = PrefixSum2D::new(&grid, 2, 3);
+    assert_eq!(ps.sum(1, 2), Ok(21));
+    assert_eq!(ps.rect_sum(0, 1, 1, 2), Ok(16));
+    assert_eq!(ps.rect_sum(1, 1, 1, 1), Ok(5));
+    assert!(ps.rect_sum(0, 0, 2, 0).is_err());
+    assert!(ps.rect_sum(1, 0, 0, 0).is_err());
+}
+
+#[test]
+fn fenwick_updates_and_rectangles() {
+    #[rustfmt::skip]
+    let grid = vec![
+        1, 2, 3,
+        4, 5, 6,
+    ];
+    let mut ft = FenwickTree2D::from(&grid, 2, 3);
+    assert_eq!(ft.rect_sum(0, 1, 1, 2), Ok(16));
+    ft.add(0, 1, 10).unwrap();
+    ft.set(1, 2, 0).unwrap();
+    assert_eq!(ft.sum(1, 2), Ok(25));
+    assert_eq!(ft.access(0, 1), Ok(12));
+    assert!(ft.add(2, 0, 1).is_err());
+}
+
+#[test]
+fn unsigned_grids() {
+    #[rustfmt::skip]
+    let grid: Vec<usize> = vec![
+        100, 100,
+        1, 1,
+    ];
+    let ps = PrefixSum2D::new(&grid, 2, 2);
+    assert_eq!(ps.rect_sum(1, 1, 1, 1), Ok(1));
+    assert_eq!(ps.rect_sum(1, 0, 1, 1), Ok(2));
+    assert_eq!(ps.rect_sum(0, 1, 1, 1), Ok(101));
+
+    let mut ft = FenwickTree2D::from(&grid, 2, 2);
+    assert_eq!(ft.rect_sum(1, 1, 1, 1), Ok(1));
+    assert_eq!(ft.access(1, 0), Ok(1));
+    //the value decreases
+    ft.set(0, 0, 3).unwrap();
+    ft.set(1, 1, 0).unwrap();
+    assert_eq!(ft.sum(1, 1), Ok(104));
+    assert_eq!(ft.rect_sum(0, 1, 1, 1), Ok(100));
+}
+
+#[test]
+fn empty_grids() {
+    let ps = PrefixSum2D::<i64>::new(&[], 0, 4);
+    assert!(ps.is_empty());
+    let ft = FenwickTree2D::<i64>::from(&[], 3, 0);
+    assert!(ft.is_empty());
+    assert!(ft.sum(0, 0).is_err());
+}
+
+/* ---------- property‑based randomized test ---------- */
+
+#[test]
+fn random_operations_against_naive() {
+    let mut rng = StdRng::seed_from_u64(42);
+    for (rows, cols) in [(1, 1), (1, 9), (7, 1), (5, 8), (16, 13)] {
+        let mut grid: Vec<i64> = (0..rows * cols).map(|_| rng.random_range(-9..10)).collect();
+        let ps = PrefixSum2D::new(&grid, rows, cols);
+        let mut ft = FenwickTree2D::from(&grid, rows, cols);
+
+        //static queries on the initial grid
+        for _ in 0..100 {
+            let (r1, c1) = (rng.random_range(0..rows), rng.random_range(0..cols));
+            let (r2, c2) = (rng.random_range(r1..rows), rng.random_range(c1..cols));
+            let expected = naive_rect(&grid, cols, r1, c1, r2, c2);
+            assert_eq!(ps.rect_sum(r1, c1, r2, c2), Ok(expected));
+            assert_eq!(ft.rect_sum(r1, c1, r2, c2), Ok(expected));
+        }
+
+        for _ in 0..300 {
+            let (r1, c1) = (rng.random_range(0..rows), rng.random_range(0..cols));
+            if rng.random_bool(0.4) {
+                let v = rng.random_range(-9..10);
+                ft.add(r1, c1, v).unwrap();
+                grid[r1 * cols + c1] += v;
+            } else {
+                let (r2, c2) = (rng.random_range(r1..rows), rng.random_range(c1..cols));
+                let expected = naive_rect(&grid, cols, r1, c1, r2, c2);
+                assert_eq!(
+                    ft.rect_sum(r1, c1, r2, c2),
+                    Ok(expected),
+                    "({r1},{c1})-({r2},{c2})"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn random_unsigned_sets_against_naive() {
+    let mut rng = StdRng::seed_from_u64(7);
+    let (rows, cols) = (9, 11);
+    let mut grid: Vec<u64> = (0..rows * cols).map(|_| rng.random_range(0..50)).collect();
+    let ps = PrefixSum2D::new(&grid, rows, cols);
+    let mut ft = FenwickTree2D::from(&grid, rows, cols);
+    let naive = |grid: &[u64], r1: usize, c1: usize, r2: usize, c2: usize| -> u64 {
+        (r1..=r2)
+            .map(|r| grid[r * cols + c1..=r * cols + c2].iter().sum::<u64>())
+            .sum()
+    };
+    for _ in 0..100 {
+        let (r1, c1) = (rng.random_range(0..rows), rng.random_range(0..cols));
+        let (r2, c2) = (rng.random_range(r1..rows), rng.random_range(c1..cols));
+        assert_eq!(
+            ps.rect_sum(r1, c1, r2, c2),
+            Ok(naive(&grid, r1, c1, r2, c2))
+        );
+    }
+    for _ in 0..500 {
+        let (r1, c1) = (rng.random_range(0..rows), rng.random_range(0..cols));
+        if rng.random_bool(0.4) {
+            let v = rng.random_range(0..50);
+            ft.set(r1, c1, v).unwrap();
+            grid[r1 * cols + c1] = v;
+        } else {
+            let (r2, c2) = (rng.random_range(r1..rows), rng.random_range(c1..cols));
+            assert_eq!(
+                ft.rect_sum(r1, c1, r2, c2),
+                Ok(naive(&grid, r1, c1, r2, c2))
+            );
+        }
+    }
+}
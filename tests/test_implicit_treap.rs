@@ -0,0 +1,125 @@
+use code::data_structs::implicit_treap::ImplicitTreap;
+use code::data_structs::monoid::{Min, Monoid, Sum};
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+/* ---------- helpers ---------- */
+
+/// A non commutative monoid: reversals must reverse the order of the combination
+struct Concat;
+
+impl Monoid for Concat {
+    type Value = String;
+
+    fn identity() -> String {
+        String::new()
+    }
+
+    fn combine(a: &String, b: &String) -> String {
+        format!("{a}{b}")
+    }
+}
+
+fn letters(s: &str) -> ImplicitTreap<Concat> {
+    s.chars().map(String::from).collect()
+}
+
+/* ---------- deterministic unit tests ---------- */
+
+#[test]
+fn insert_remove_and_query() {
+    let mut t: ImplicitTreap<Sum<i64>> = (1..=5).collect();
+    assert_eq!(t.len(), 5);
+    assert_eq!(t.query(0, 4), 15);
+    t.insert_at(2, 10); // [1,2,10,3,4,5]
+    t.insert_at(0, -1); // [-1,1,2,10,3,4,5]
+    t.insert_at(7, 7); // [-1,1,2,10,3,4,5,7]
+    assert_eq!(t.query(2, 4), 15);
+    assert_eq!(t.remove_at(3), 10);
+    assert_eq!(t.get(3), 3);
+    assert_eq!(t.to_vec(), vec![-1, 1, 2, 3, 4, 5, 7]);
+}
+
+#[test]
+fn reverse_non_commutative() {
+    let mut t = letters("abcdefg");
+    t.reverse(1, 4); // aedcbfg
+    assert_eq!(t.query(0, 6), "aedcbfg");
+    assert_eq!(t.query(2, 5), "dcbf");
+    t.reverse(0, 6); // gfbcdea
+    assert_eq!(t.query(0, 2), "gfb");
+    t.reverse(3, 3);
+    assert_eq!(t.to_vec().concat(), "gfbcdea");
+}
+
+#[test]
+fn split_and_merge() {
+    let t = letters("hello world");
+    let (hello, rest) = t.split_at(5);
+    let (space, mut world) = rest.split_at(1);
+    assert_eq!(space.len(), 1);
+    world.reverse(0, 4);
+    let mut t = world.merge(space).merge(hello);
+    assert_eq!(t.query(0, 10), "dlrow hello");
+
+    let (empty, all) = t.split_at(0);
+    assert!(empty.is_empty());
+    let (mut all, empty) = all.split_at(11);
+    assert!(empty.is_empty());
+    assert_eq!(all.query(0, 10), "dlrow hello");
+}
+
+#[test]
+#[should_panic(expected = "Range not supported")]
+fn out_of_bounds_query() {
+    let mut t: ImplicitTreap<Min<i32>> = [1, 2, 3].into_iter().collect();
+    t.query(1, 3);
+}
+
+/* ---------- property‑based randomized test ---------- */
+
+#[test]
+fn random_operations_against_vec() {
+    let mut rng = StdRng::seed_from_u64(42);
+    let mut t: ImplicitTreap<Concat> = ImplicitTreap::new();
+    let mut v: Vec<String> = vec![];
+    for step in 0..3000 {
+        let len = v.len();
+        match rng.random_range(0..10) {
+            0..3 => {
+                let i = rng.random_range(0..=len);
+                let c = char::from(b'a' + rng.random_range(0..26u8)).to_string();
+                t.insert_at(i, c.clone());
+                v.insert(i, c);
+            }
+            3 if len > 0 => {
+                let i = rng.random_range(0..len);
+                assert_eq!(t.remove_at(i), v.remove(i));
+            }
+            4..6 if len > 0 => {
+                let l = rng.random_range(0..len);
+                let r = rng.random_range(l..len);
+                t.reverse(l, r);
+                v[l..=r].reverse();
+            }
+            6 if len > 0 => {
+                //split in three and merge back in a different order
+                let a = rng.random_range(0..=len);
+                let b = rng.random_range(a..=len);
+                let (x, rest) = std::mem::take(&mut t).split_at(a);
+                let (y, z) = rest.split_at(b - a);
+                t = z.merge(y).merge(x);
+                let (x, rest) = v.split_at(a);
+                let (y, z) = rest.split_at(b - a);
+                v = [z, y, x].concat();
+            }
+            _ if len > 0 => {
+                let l = rng.random_range(0..len);
+                let r = rng.random_range(l..len);
+                assert_eq!(t.query(l, r), v[l..=r].concat(), "step {step}");
+            }
+            _ => {}
+        }
+        assert_eq!(t.len(), v.len());
+    }
+    assert_eq!(t.to_vec(), v);
+}
@@ -0,0 +1,99 @@
+use code::data_structs::iterative_segment_tree::IterativeSegmentTree;
+use code::data_structs::monoid::{Max, Min, Monoid, Sum};
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+/* ---------- helpers ---------- */
+
+/// Slow but simple reference implementation for sanity‑checking.
+fn naive_query<M: Monoid>(data: &[M::Value], ql: usize, qr: usize) -> M::Value {
+    let fold = |acc: M::Value, v: &M::Value| M::combine(&acc, v);
+    if ql <= qr {
+        data[ql..=qr].iter().fold(M::identity(), fold)
+    } else {
+        // wrap‑around query
+        let tail = data[ql..].iter().fold(M::identity(), fold);
+        data[..=qr].iter().fold(tail, fold)
+    }
+}
+
+/// A non commutative monoid: the tree must combine left to right
+struct Concat;
+
+impl Monoid for Concat {
+    type Value = String;
+
+    fn identity() -> String {
+        String::new()
+    }
+
+    fn combine(a: &String, b: &String) -> String {
+        format!("{a}{b}")
+    }
+}
+
+/* ---------- deterministic unit tests ---------- */
+
+#[test]
+fn empty_input_does_not_panic() {
+    let st: IterativeSegmentTree<Sum<i64>> = std::iter::empty().collect();
+    assert!(st.is_empty());
+    let st: IterativeSegmentTree<Min<i64>> = IterativeSegmentTree::build(&[]);
+    assert_eq!(st.len(), 0);
+}
+
+#[test]
+fn from_iter_and_queries() {
+    let st: IterativeSegmentTree<Sum<i64>> = (1..=7).collect();
+    assert_eq!(st.len(), 7);
+    assert_eq!(st.query(0, 6), 28);
+    assert_eq!(st.query(2, 4), 12);
+    assert_eq!(st.query(5, 1), 16); // wrap: 6 + 7 + 1 + 2
+    assert_eq!(st.get(3), 4);
+}
+
+#[test]
+fn set_then_query() {
+    let mut st: IterativeSegmentTree<Max<i32>> = IterativeSegmentTree::build(&[5, 3, 8, 7, 1]);
+    assert_eq!(st.query(0, 4), 8);
+    st.set(2, 0);
+    assert_eq!(st.query(0, 4), 7);
+    assert_eq!(st.query(1, 2), 3);
+}
+
+#[test]
+fn non_commutative_order() {
+    for n in 1..20 {
+        let data: Vec<String> = (0..n)
+            .map(|i| ((b'a' + i as u8) as char).to_string())
+            .collect();
+        let st: IterativeSegmentTree<Concat> = IterativeSegmentTree::build(&data);
+        for l in 0..n {
+            for r in 0..n {
+                assert_eq!(st.query(l, r), naive_query::<Concat>(&data, l, r));
+            }
+        }
+    }
+}
+
+/* ---------- property‑based randomized test ---------- */
+
+#[test]
+fn random_operations_against_naive() {
+    const OPS: usize = 300;
+    let mut rng = StdRng::seed_from_u64(42);
+
+    for n in [1, 2, 3, 17, 32, 45] {
+        let mut data: Vec<i64> = (0..n).map(|_| rng.random_range(-50..50)).collect();
+        let mut st: IterativeSegmentTree<Min<i64>> = IterativeSegmentTree::build(&data);
+        for _ in 0..OPS {
+            if rng.random_bool(0.4) {
+                let (i, v) = (rng.random_range(0..n), rng.random_range(-50..50));
+                st.set(i, v);
+                data[i] = v;
+            } else {
+                let (l, r) = (rng.random_range(0..n), rng.random_range(0..n));
+                assert_eq!(st.query(l, r), naive_query::<Min<i64>>(&data, l, r));
+            }
+        }
+    }
+}
@@ -0,0 +1,178 @@
+use code::data_structs::action::{Action, Add, Affine, Assign, Chmax, Chmin};
+use code::data_structs::lazy_segment_tree::LazySegmentTree;
+use code::data_structs::monoid::{Max, Min, Monoid, Sum};
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+/* ---------- helpers ---------- */
+
+/// Indices covered by a (possibly circular) range
+fn range_indices(n: usize, l: usize, r: usize) -> Vec<usize> {
+    if l <= r {
+        (l..=r).collect()
+    } else {
+        (l..n).chain(0..=r).collect()
+    }
+}
+
+fn expect_tree_matches_array(tree: &mut LazySegmentTree<Min<i64>, Add<i64>>, arr: &[i64]) {
+    for (i, &v) in arr.iter().enumerate() {
+        assert_eq!(tree.get(i), v, "leaf mismatch at index {i}");
+    }
+}
+
+///
+/// Random range updates, point assignments and range queries, checked against a plain
+/// array where `update` applies a tag to a single element
+fn random_operations<M, A>(
+    rng: &mut StdRng,
+    mut gen_value: impl FnMut(&mut StdRng) -> M::Value,
+    mut gen_tag: impl FnMut(&mut StdRng) -> A::Value,
+    update: impl Fn(&A::Value, &M::Value) -> M::Value,
+) where
+    M: Monoid,
+    A: Action<M>,
+    M::Value: PartialEq + std::fmt::Debug,
+{
+    const N: usize = 29;
+    const OPS: usize = 400;
+
+    let mut data: Vec<M::Value> = (0..N).map(|_| gen_value(rng)).collect();
+    let mut st: LazySegmentTree<M, A> = LazySegmentTree::build(&data);
+
+    for _ in 0..OPS {
+        let l = rng.random_range(0..N);
+        let r = rng.random_range(0..N);
+        match rng.random_range(0..10) {
+            0..4 => {
+                let f = gen_tag(rng);
+                st.apply(l, r, f.clone());
+                for i in range_indices(N, l, r) {
+                    data[i] = update(&f, &data[i]);
+                }
+            }
+            4 => {
+                let v = gen_value(rng);
+                st.set(l, v.clone());
+                data[l] = v;
+            }
+            _ => {
+                let expected = range_indices(N, l, r)
+                    .into_iter()
+                    .fold(M::identity(), |acc, i| M::combine(&acc, &data[i]));
+                assert_eq!(st.query(l, r), expected, "query=({l},{r})");
+            }
+        }
+    }
+}
+
+/* ---------- deterministic unit tests ---------- */
+
+#[test]
+fn build_and_point_queries() {
+    let data = vec![3, 1, 4, 1, 5, 9, 2];
+    let mut st: LazySegmentTree<Min<i64>, Add<i64>> = LazySegmentTree::build(&data);
+    assert_eq!(st.len(), data.len());
+    expect_tree_matches_array(&mut st, &data);
+
+    // global min should match std::iter::min
+    assert_eq!(st.query(0, data.len() - 1), *data.iter().min().unwrap());
+}
+
+#[test]
+fn range_add_then_query() {
+    let mut st: LazySegmentTree<Min<i64>, Add<i64>> = LazySegmentTree::build(&[0, 0, 0, 0]);
+    st.apply(1, 3, 10); // [0,10,10,10]
+    st.apply(0, 0, -5); // [-5,10,10,10]
+    assert_eq!(st.query(0, 3), -5);
+    assert_eq!(st.query(1, 2), 10);
+    expect_tree_matches_array(&mut st, &[-5, 10, 10, 10]);
+}
+
+#[test]
+fn wrap_around_update_and_query() {
+    let mut st: LazySegmentTree<Min<i64>, Add<i64>> = LazySegmentTree::build(&[7, 7, 7, 7, 7]);
+    st.apply(3, 1, -2); // affects indices 3,4,0,1
+    assert_eq!(st.query(4, 2), 5); // wrap query too
+    expect_tree_matches_array(&mut st, &[5, 5, 7, 5, 5]);
+}
+
+#[test]
+fn overlapping_updates_idempotent() {
+    let mut st: LazySegmentTree<Min<i64>, Add<i64>> = LazySegmentTree::build(&[1, 1, 1, 1]);
+    st.apply(0, 3, 3);
+    st.apply(1, 2, -1);
+    assert_eq!(st.query(0, 3), 3); // [4,3,3,4]
+    expect_tree_matches_array(&mut st, &[4, 3, 3, 4]);
+}
+
+#[test]
+fn range_add_on_sum() {
+    let mut st: LazySegmentTree<Sum<i64>, Add<i64>> = LazySegmentTree::build(&[0, 0, 0, 0, 0]);
+    st.apply(0, 2, 1);
+    assert_eq!(st.query(0, 2), 3);
+    assert_eq!(st.query(1, 4), 2);
+    st.apply(3, 1, 2); // indices 3,4,0,1
+    assert_eq!(st.query(0, 4), 11);
+}
+
+#[test]
+fn range_assign_on_sum() {
+    let mut st: LazySegmentTree<Sum<i64>, Assign<i64>> =
+        LazySegmentTree::build(&[1, 2, 3, 4, 5, 6]);
+    st.apply(1, 4, Some(10));
+    assert_eq!(st.query(0, 5), 47);
+    st.apply(3, 5, Some(0));
+    assert_eq!(st.query(0, 5), 21);
+    assert_eq!(st.get(2), 10);
+}
+
+#[test]
+fn chmin_on_max() {
+    let mut st: LazySegmentTree<Max<i32>, Chmin<i32>> = LazySegmentTree::build(&[5, 3, 8, 7]);
+    assert_eq!(st.query(0, 3), 8);
+    st.apply(1, 2, 4); // clamp 3 and 8 to min(x,4)
+    assert_eq!(st.get(1), 3);
+    assert_eq!(st.get(2), 4);
+    assert_eq!(st.query(0, 3), 7);
+}
+
+#[test]
+fn affine_on_sum() {
+    let mut st: LazySegmentTree<Sum<i64>, Affine<i64>> = LazySegmentTree::build(&[1, 2, 3, 4]);
+    st.apply(0, 3, (2, 1)); // [3,5,7,9]
+    st.apply(1, 2, (-1, 4)); // [3,-1,-3,9]
+    assert_eq!(st.query(0, 3), 8);
+    assert_eq!(st.query(1, 2), -4);
+    assert_eq!(st.get(3), 9);
+}
+
+/* ---------- property‑based randomized test ---------- */
+
+#[test]
+fn random_operations_against_naive() {
+    let seed = 42; // keep deterministic; change for new scenarios
+    let mut rng = StdRng::seed_from_u64(seed);
+    let value = |r: &mut StdRng| r.random_range(-50..50);
+    let delta = |r: &mut StdRng| r.random_range(-20..20);
+    let assign = |r: &mut StdRng| Some(r.random_range(-50..50));
+
+    random_operations::<Min<i64>, Add<i64>>(&mut rng, value, delta, |f, x| x + f);
+    random_operations::<Max<i64>, Add<i64>>(&mut rng, value, delta, |f, x| x + f);
+    random_operations::<Sum<i64>, Add<i64>>(&mut rng, value, delta, |f, x| x + f);
+
+    random_operations::<Min<i64>, Assign<i64>>(&mut rng, value, assign, |f, x| f.unwrap_or(*x));
+    random_operations::<Max<i64>, Assign<i64>>(&mut rng, value, assign, |f, x| f.unwrap_or(*x));
+    random_operations::<Sum<i64>, Assign<i64>>(&mut rng, value, assign, |f, x| f.unwrap_or(*x));
+
+    random_operations::<Min<i64>, Chmin<i64>>(&mut rng, value, value, |f, x| *x.min(f));
+    random_operations::<Max<i64>, Chmin<i64>>(&mut rng, value, value, |f, x| *x.min(f));
+    random_operations::<Min<i64>, Chmax<i64>>(&mut rng, value, value, |f, x| *x.max(f));
+    random_operations::<Max<i64>, Chmax<i64>>(&mut rng, value, value, |f, x| *x.max(f));
+
+    random_operations::<Sum<i64>, Affine<i64>>(
+        &mut rng,
+        value,
+        |r| (r.random_range(-1..2), r.random_range(-5..5)),
+        |f, x| f.0 * x + f.1,
+    );
+}
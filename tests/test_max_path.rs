@@ -1,6 +1,6 @@
 #![allow(unused_imports)]
 use code::data_structs::tree::TreeNode;
-use code::optional::set4::max_path::max_path;
+use code::optional::set4::max_path::{max_path, max_path_with_path};
 use code::test_util::TestCase;
 use code::{tree, tree_leaf, tree_left, tree_right};
 
@@ -47,3 +47,16 @@ fn test_leaf_to_root() {
     let t = tree_right!(1, tree_leaf!(2));
     TestC::new(&t, Some(3)).test(max_path);
 }
+
+#[test]
+fn test_with_path() {
+    let t = tree_leaf!(10);
+    TestCase::new(&t, None).test(max_path_with_path);
+
+    let t = tree!(3, tree!(4, tree_leaf!(-10), tree_leaf!(4)), tree_leaf!(5));
+    TestCase::new(&t, Some((16, vec![4, 4, 3, 5]))).test(max_path_with_path);
+
+    //the root with a single child is a special node, it ends the path coming up from the left
+    let t = tree_left!(1, tree!(2, tree_leaf!(-5), tree_leaf!(-4)));
+    TestCase::new(&t, Some((-1, vec![-4, 2, 1]))).test(max_path_with_path);
+}
@@ -3,12 +3,16 @@ use code::optional::set2::max_sliding_window::*;
 use code::test_case;
 use code::test_util::*;
 
-const TO_TEST: [fn(&[i32], usize) -> Option<Vec<i32>>; 5] = [
+type Solver = fn(&[i32], usize) -> Option<Vec<i32>>;
+
+const TO_TEST: [Solver; 7] = [
     max_sliding_bruteforce,
     max_sliding_ideomatic,
     max_sliding_window_bst,
     max_sliding_window_heap,
     max_sliding_window_deque,
+    max_sliding_window_sparse_table,
+    max_sliding_window_two_stacks,
 ];
 
 #[test]
@@ -5,7 +5,9 @@ use code::test_util::TestCase;
 type TestC<'a> = TestCase<&'a [i32], Option<i32>>;
 type TestCt<'a> = TestCase<&'a [i32], Option<(i32, usize, usize)>>;
 
-const FUN: [fn(&[i32]) -> Option<i32>; 3] = [
+type Solver = fn(&[i32]) -> Option<i32>;
+
+const FUN: [Solver; 3] = [
     max_subarray_bruteforce,
     max_subarray_bruteforce_optimized,
     kadane,
@@ -0,0 +1,54 @@
+use code::data_structs::merge_sort_tree::MergeSortTree;
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+/* ---------- deterministic unit tests ---------- */
+
+#[test]
+fn count_range_and_count_less() {
+    let a = [5, 1, 4, 1, 5, 9, 2, 6];
+    let mst = MergeSortTree::new(&a);
+    assert_eq!(mst.len(), 8);
+    assert_eq!(mst.count_range(0, 7, 1, 5), 6);
+    assert_eq!(mst.count_range(1, 6, 2, 5), 3); // 4, 5, 2
+    assert_eq!(mst.count_range(5, 5, 9, 9), 1);
+    assert_eq!(mst.count_range(0, 7, 7, 8), 0);
+    assert_eq!(mst.count_range(0, 7, 6, 2), 0);
+    assert_eq!(mst.count_less(0, 7, 5), 4);
+    assert_eq!(mst.count_less(2, 4, 100), 3);
+}
+
+#[test]
+fn empty_input_does_not_panic() {
+    let mst = MergeSortTree::<i32>::new(&[]);
+    assert!(mst.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "Range not supported")]
+fn out_of_bounds_query() {
+    MergeSortTree::new(&[1, 2, 3]).count_range(1, 3, 0, 5);
+}
+
+/* ---------- property‑based randomized test ---------- */
+
+#[test]
+fn random_queries_against_naive() {
+    let mut rng = StdRng::seed_from_u64(42);
+    for n in [1, 2, 3, 7, 16, 45] {
+        let a: Vec<i64> = (0..n).map(|_| rng.random_range(-20..20)).collect();
+        let mst = MergeSortTree::new(&a);
+        for _ in 0..500 {
+            let l = rng.random_range(0..n);
+            let r = rng.random_range(l..n);
+            let (lo, hi) = (rng.random_range(-25..25), rng.random_range(-25..25));
+            let expected = a[l..=r].iter().filter(|&&v| lo <= v && v <= hi).count();
+            assert_eq!(
+                mst.count_range(l, r, lo, hi),
+                expected,
+                "({l},{r},{lo},{hi})"
+            );
+            let expected = a[l..=r].iter().filter(|&&v| v < hi).count();
+            assert_eq!(mst.count_less(l, r, hi), expected, "({l},{r},{hi})");
+        }
+    }
+}
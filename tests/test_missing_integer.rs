@@ -2,7 +2,9 @@
 use code::optional::set1::missing_integer::*;
 use code::test_util::TestCase;
 
-const TO_TEST: [fn(&[u32]) -> Option<u32>; 4] = [
+type Solver = fn(&[u32]) -> Option<u32>;
+
+const TO_TEST: [Solver; 4] = [
     missing_integer_gauss,
     missing_integer_mark,
     missing_integer_xor,
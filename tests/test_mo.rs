@@ -0,0 +1,175 @@
+use code::data_structs::mo::{self, MoState, QueryOrder};
+use code::mandatory::powerful_array::{Operation, powerful_array, powerful_array_with_assignments};
+use code::optional::set10::distinct_values::{distinct_values, k_good_segments};
+use code::optional::set10::power_array::{Power, power_array};
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use std::collections::{HashMap, HashSet};
+
+/* ---------- helpers ---------- */
+
+fn naive_power(a: &[i64], l: usize, r: usize) -> i64 {
+    let mut frequency: HashMap<i64, i64> = HashMap::new();
+    a[l..=r]
+        .iter()
+        .for_each(|&x| *frequency.entry(x).or_insert(0) += 1);
+    frequency.iter().map(|(x, c)| x * c * c).sum()
+}
+
+fn naive_distinct(a: &[i64], l: usize, r: usize) -> usize {
+    a[l..=r].iter().collect::<HashSet<_>>().len()
+}
+
+fn random_queries(rng: &mut StdRng, n: usize, q: usize) -> Vec<(usize, usize)> {
+    (0..q)
+        .map(|_| {
+            let l = rng.random_range(0..n);
+            (l, rng.random_range(l..n))
+        })
+        .collect()
+}
+
+/// Records the window as a set of indices, checking that the driver never removes an
+/// index outside of it or adds one already inside
+struct Window(Vec<bool>);
+
+impl MoState for Window {
+    type Answer = Vec<usize>;
+
+    fn add(&mut self, i: usize) {
+        assert!(!self.0[i], "index {i} added twice");
+        self.0[i] = true;
+    }
+
+    fn remove(&mut self, i: usize) {
+        assert!(self.0[i], "index {i} removed but not in the window");
+        self.0[i] = false;
+    }
+
+    fn answer(&self) -> Vec<usize> {
+        (0..self.0.len()).filter(|&i| self.0[i]).collect()
+    }
+}
+
+/* ---------- deterministic unit tests ---------- */
+
+#[test]
+fn windows_match_queries() {
+    let mut rng = StdRng::seed_from_u64(1);
+    for order in [QueryOrder::Block, QueryOrder::Hilbert] {
+        for n in [1, 2, 3, 10, 50] {
+            let queries = random_queries(&mut rng, n, 40);
+            let answers = mo::solve_with_order(n, &queries, &mut Window(vec![false; n]), order);
+            for ((l, r), window) in queries.into_iter().zip(answers) {
+                assert_eq!(window, (l..=r).collect::<Vec<_>>());
+            }
+        }
+    }
+}
+
+#[test]
+fn no_queries() {
+    assert!(mo::solve(3, &[], &mut Window(vec![false; 3])).is_empty());
+    assert!(powerful_array(&[], &[]).is_empty());
+}
+
+#[test]
+#[should_panic(expected = "Range not supported")]
+fn out_of_bounds_query() {
+    mo::solve(3, &[(1, 3)], &mut Window(vec![false; 3]));
+}
+
+#[test]
+fn short_arrays() {
+    //the block size of sqrt(n) would be 0 for n < 4
+    assert_eq!(powerful_array(&[5], &[(0, 0)]), vec![5]);
+    assert_eq!(
+        power_array(&[1, 2, 1], &[(0, 2), (1, 1), (0, 1)]),
+        vec![6, 2, 3]
+    );
+}
+
+#[test]
+fn left_pointer_moves_right() {
+    //moving the left end must remove the leftmost item, not the rightmost one
+    let a = [1, 2, 3, 4, 5, 6, 7, 8, 9];
+    let queries = [(0, 8), (4, 8), (7, 8)];
+    assert_eq!(powerful_array(&a, &queries), vec![45, 35, 17]);
+}
+
+#[test]
+fn distinct_and_k_good() {
+    let a = [6, 5, 1, 2, 3, 2, 1, 4, 5];
+    let queries = [(0, 8), (2, 6), (3, 5), (4, 4)];
+    assert_eq!(distinct_values(&a, &queries), vec![6, 3, 2, 1]);
+    assert_eq!(
+        k_good_segments(&a, 3, &queries),
+        vec![false, true, true, true]
+    );
+}
+
+#[test]
+fn assignments_between_queries() {
+    let ops = [
+        Operation::Power(0, 3),  // [1,2,1,3] -> 4 + 2 + 3
+        Operation::Assign(1, 1), // [1,1,1,3]
+        Operation::Power(0, 3),  // 9 + 3
+        Operation::Power(1, 2),  // 4
+        Operation::Assign(3, 1), // [1,1,1,1]
+        Operation::Assign(0, 2), // [2,1,1,1]
+        Operation::Power(0, 3),  // 2 + 9
+    ];
+    assert_eq!(
+        powerful_array_with_assignments(&[1, 2, 1, 3], &ops),
+        vec![9, 12, 4, 11]
+    );
+}
+
+/* ---------- property‑based randomized test ---------- */
+
+#[test]
+fn random_queries_against_naive() {
+    let mut rng = StdRng::seed_from_u64(42);
+    for n in [1, 2, 3, 4, 17, 100] {
+        let a: Vec<i64> = (0..n).map(|_| rng.random_range(-5..6)).collect();
+        let queries = random_queries(&mut rng, n, 200);
+
+        let power: Vec<i64> = queries
+            .iter()
+            .map(|&(l, r)| naive_power(&a, l, r))
+            .collect();
+        let distinct: Vec<usize> = queries
+            .iter()
+            .map(|&(l, r)| naive_distinct(&a, l, r))
+            .collect();
+
+        assert_eq!(powerful_array(&a, &queries), power);
+        assert_eq!(power_array(&a, &queries), power);
+        let hilbert = mo::solve_with_order(n, &queries, &mut Power::new(&a), QueryOrder::Hilbert);
+        assert_eq!(hilbert, power);
+        assert_eq!(distinct_values(&a, &queries), distinct);
+    }
+}
+
+#[test]
+fn random_assignments_against_naive() {
+    let mut rng = StdRng::seed_from_u64(7);
+    for n in [1, 2, 3, 10, 60] {
+        let a: Vec<i64> = (0..n).map(|_| rng.random_range(-4..5)).collect();
+        let mut current = a.clone();
+        let mut ops = vec![];
+        let mut expected = vec![];
+        for _ in 0..300 {
+            let l = rng.random_range(0..n);
+            if rng.random_bool(0.4) {
+                let x = rng.random_range(-4..5);
+                ops.push(Operation::Assign(l, x));
+                current[l] = x;
+            } else {
+                let r = rng.random_range(l..n);
+                ops.push(Operation::Power(l, r));
+                expected.push(naive_power(&current, l, r));
+            }
+        }
+        assert_eq!(powerful_array_with_assignments(&a, &ops), expected, "n={n}");
+    }
+}
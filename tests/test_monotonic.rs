@@ -0,0 +1,175 @@
+use code::data_structs::monotonic::{
+    MonotonicQueue, MonotonicStack, next_by, next_greater, next_smaller, previous_greater,
+    previous_smaller, sliding_by, sliding_max, sliding_min,
+};
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use std::cell::Cell;
+
+/* ---------- helpers ---------- */
+
+/// Nearest previous index `j` with `keeps(a[j], a[i])`, by scanning
+fn naive_previous(a: &[i32], keeps: impl Fn(&i32, &i32) -> bool) -> Vec<Option<usize>> {
+    (0..a.len())
+        .map(|i| (0..i).rev().find(|&j| keeps(&a[j], &a[i])))
+        .collect()
+}
+
+/// Nearest next index `j` with `keeps(a[j], a[i])`, by scanning
+fn naive_next(a: &[i32], keeps: impl Fn(&i32, &i32) -> bool) -> Vec<Option<usize>> {
+    (0..a.len())
+        .map(|i| (i + 1..a.len()).find(|&j| keeps(&a[j], &a[i])))
+        .collect()
+}
+
+/* ---------- deterministic unit tests ---------- */
+
+#[test]
+fn previous_and_next_queries() {
+    let a = [7, 2, 5, 3, 4, 5, 1];
+    assert_eq!(
+        previous_greater(&a),
+        vec![None, Some(0), Some(0), Some(2), Some(2), Some(0), Some(5)]
+    );
+    assert_eq!(
+        next_greater(&a),
+        vec![None, Some(2), None, Some(4), Some(5), None, None]
+    );
+    assert_eq!(
+        previous_smaller(&a),
+        vec![None, None, Some(1), Some(1), Some(3), Some(4), None]
+    );
+    assert_eq!(
+        next_smaller(&a),
+        vec![Some(1), Some(6), Some(3), Some(6), Some(6), Some(6), None]
+    );
+    assert!(next_greater::<i32>(&[]).is_empty());
+}
+
+#[test]
+fn stack_push_and_pop() {
+    let mut s = MonotonicStack::max();
+    assert_eq!(s.push(5), None);
+    assert_eq!(s.push(3), Some(0));
+    assert_eq!(s.push(3), Some(0)); //the first 3 is evicted
+    assert_eq!(s.len(), 2);
+    assert_eq!(s.pop_dominated(&1), None);
+    assert_eq!(s.pop_dominated(&4), Some((2, 3)));
+    assert_eq!(s.top(), Some((0, &5)));
+    assert_eq!(s.push(9), None);
+    assert_eq!(s.len(), 1);
+}
+
+#[test]
+fn queue_max_and_min() {
+    let mut q = MonotonicQueue::max();
+    assert!(q.is_empty());
+    assert_eq!(q.peek(), None);
+    for v in [3, 1, 4, 1, 5] {
+        q.push(v);
+    }
+    assert_eq!(q.len(), 5);
+    assert_eq!(q.peek(), Some(&5));
+
+    let mut q = MonotonicQueue::min();
+    for v in [3, 1, 4, 1, 5] {
+        q.push(v);
+    }
+    assert_eq!(q.peek(), Some(&1));
+    q.pop_front(); //3
+    q.pop_front(); //1 (evicted by the second 1)
+    assert_eq!(q.peek(), Some(&1));
+    q.pop_front(); //4
+    q.pop_front(); //1
+    assert_eq!(q.peek(), Some(&5));
+    q.pop_front();
+    assert!(q.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "Queue is empty")]
+fn pop_empty_queue() {
+    MonotonicQueue::<i32, _>::max().pop_front();
+}
+
+#[test]
+fn sliding_windows() {
+    let a = [7, 2, 5, 3, 4, 3];
+    assert_eq!(sliding_max(a, 3).collect::<Vec<_>>(), vec![7, 5, 5, 4]);
+    assert_eq!(sliding_min(a, 3).collect::<Vec<_>>(), vec![2, 2, 3, 3]);
+    assert_eq!(sliding_max(a, 1).collect::<Vec<_>>(), a.to_vec());
+    assert_eq!(sliding_max(a, 6).collect::<Vec<_>>(), vec![7]);
+    assert_eq!(sliding_max(a, 7).count(), 0);
+    assert_eq!(sliding_max(a, 0).count(), 0);
+
+    let words = ["pear", "fig", "apple", "kiwi"];
+    let longest = sliding_by(words, 2, |a: &&str, b: &&str| a.len() > b.len());
+    assert_eq!(longest.collect::<Vec<_>>(), vec!["pear", "apple", "apple"]);
+}
+
+#[test]
+fn sliding_is_lazy() {
+    //an infinite source: only the items of the windows requested are consumed
+    let consumed = Cell::new(0);
+    let source = (0..).map(|i| {
+        consumed.set(consumed.get() + 1);
+        (i * 7) % 10
+    });
+    let first: Vec<i32> = sliding_max(source, 4).take(3).collect();
+    assert_eq!(first, vec![7, 8, 8]);
+    assert_eq!(consumed.get(), 6);
+}
+
+/* ---------- property‑based randomized test ---------- */
+
+#[test]
+fn random_previous_next_against_naive() {
+    let mut rng = StdRng::seed_from_u64(42);
+    for _ in 0..200 {
+        let n = rng.random_range(0..60);
+        let a: Vec<i32> = (0..n).map(|_| rng.random_range(0..10)).collect();
+        assert_eq!(previous_greater(&a), naive_previous(&a, |p, v| p > v));
+        assert_eq!(previous_smaller(&a), naive_previous(&a, |p, v| p < v));
+        assert_eq!(next_greater(&a), naive_next(&a, |n, v| n > v));
+        assert_eq!(next_smaller(&a), naive_next(&a, |n, v| n < v));
+        //non strict variant
+        assert_eq!(next_by(&a, |n, v| n >= v), naive_next(&a, |n, v| n >= v));
+    }
+}
+
+#[test]
+fn random_sliding_against_windows() {
+    let mut rng = StdRng::seed_from_u64(7);
+    for _ in 0..200 {
+        let n = rng.random_range(0..80);
+        let a: Vec<i32> = (0..n).map(|_| rng.random_range(-20..20)).collect();
+        let k = rng.random_range(0..n + 2);
+        let (max, min): (Vec<i32>, Vec<i32>) = if k == 0 {
+            (vec![], vec![])
+        } else {
+            a.windows(k)
+                .map(|w| (*w.iter().max().unwrap(), *w.iter().min().unwrap()))
+                .unzip()
+        };
+        assert_eq!(sliding_max(a.iter().copied(), k).collect::<Vec<_>>(), max);
+        assert_eq!(sliding_min(a.iter().copied(), k).collect::<Vec<_>>(), min);
+    }
+}
+
+#[test]
+fn random_queue_operations() {
+    let mut rng = StdRng::seed_from_u64(42);
+    let mut q = MonotonicQueue::max();
+    let mut naive = std::collections::VecDeque::new();
+    for _ in 0..5000 {
+        if naive.is_empty() || rng.random_bool(0.6) {
+            let v = rng.random_range(0..100);
+            q.push(v);
+            naive.push_back(v);
+        } else {
+            q.pop_front();
+            naive.pop_front();
+        }
+        assert_eq!(q.len(), naive.len());
+        assert_eq!(q.peek(), naive.iter().max());
+    }
+}
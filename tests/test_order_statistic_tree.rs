@@ -0,0 +1,121 @@
+use code::data_structs::order_statistic_tree::OrderStatisticTree;
+use code::optional::set2::sliding_window_median::sliding_window_median;
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use std::collections::BTreeSet;
+
+/* ---------- deterministic unit tests ---------- */
+
+#[test]
+fn insert_remove_rank_select() {
+    let mut t = OrderStatisticTree::new();
+    for v in [50, 20, 80, 10, 30, 70] {
+        assert!(t.insert(v));
+    }
+    assert!(!t.insert(30));
+    assert_eq!(t.len(), 6);
+    assert_eq!(t.rank(&30), 2);
+    assert_eq!(t.rank(&35), 3);
+    assert_eq!(t.rank(&5), 0);
+    assert_eq!(t.select(0), Some(&10));
+    assert_eq!(t.select(4), Some(&70));
+    assert_eq!(t.select(6), None);
+
+    assert!(t.remove(&50));
+    assert!(!t.remove(&50));
+    assert!(!t.contains(&50));
+    assert_eq!(t.select(3), Some(&70));
+    assert_eq!(
+        t.iter().copied().collect::<Vec<_>>(),
+        vec![10, 20, 30, 70, 80]
+    );
+}
+
+#[test]
+fn range_iteration() {
+    let t: OrderStatisticTree<i32> = {
+        let mut t = OrderStatisticTree::new();
+        (0..20).map(|v| v * 3).for_each(|v| {
+            t.insert(v);
+        });
+        t
+    };
+    assert_eq!(
+        t.range(10..20).copied().collect::<Vec<_>>(),
+        vec![12, 15, 18]
+    );
+    assert_eq!(
+        t.range(9..=18).copied().collect::<Vec<_>>(),
+        vec![9, 12, 15, 18]
+    );
+    assert_eq!(t.range(..4).copied().collect::<Vec<_>>(), vec![0, 3]);
+    assert_eq!(t.range(55..).copied().collect::<Vec<_>>(), vec![57]);
+    assert_eq!(t.range(100..).count(), 0);
+}
+
+#[test]
+fn empty_tree() {
+    let t = OrderStatisticTree::<i32>::default();
+    assert!(t.is_empty());
+    assert_eq!(t.rank(&3), 0);
+    assert_eq!(t.select(0), None);
+    assert_eq!(t.iter().count(), 0);
+}
+
+#[test]
+fn sliding_median() {
+    assert_eq!(
+        sliding_window_median(&[7, 2, 5, 3, 4, 3], 3),
+        Some(vec![5, 3, 4, 3])
+    );
+    assert_eq!(
+        sliding_window_median(&[1, 3, -1, -3, 5, 3, 6, 7], 4),
+        Some(vec![-1, -1, -1, 3, 5])
+    );
+    assert_eq!(sliding_window_median(&[1, 1, 1], 2), Some(vec![1, 1]));
+    assert_eq!(sliding_window_median(&[1, 2], 3), None);
+    assert_eq!(sliding_window_median(&[1, 2], 0), None);
+}
+
+/* ---------- property‑based randomized test ---------- */
+
+#[test]
+fn random_operations_against_btreeset() {
+    let mut rng = StdRng::seed_from_u64(42);
+    let mut t = OrderStatisticTree::new();
+    let mut set = BTreeSet::new();
+    for _ in 0..3000 {
+        let v = rng.random_range(-100..100);
+        match rng.random_range(0..4) {
+            0 => assert_eq!(t.insert(v), set.insert(v)),
+            1 => assert_eq!(t.remove(&v), set.remove(&v)),
+            2 => {
+                assert_eq!(t.rank(&v), set.range(..v).count());
+                let k = rng.random_range(0..set.len() + 1);
+                assert_eq!(t.select(k), set.iter().nth(k));
+            }
+            _ => {
+                let hi = rng.random_range(v..101);
+                assert!(t.range(v..hi).eq(set.range(v..hi)));
+            }
+        }
+        assert_eq!(t.len(), set.len());
+    }
+    assert!(t.iter().eq(set.iter()));
+}
+
+#[test]
+fn random_sliding_median_against_naive() {
+    let mut rng = StdRng::seed_from_u64(7);
+    let nums: Vec<i32> = (0..200).map(|_| rng.random_range(-20..20)).collect();
+    for k in [1, 2, 5, 16, 200] {
+        let expected: Vec<i32> = nums
+            .windows(k)
+            .map(|w| {
+                let mut w = w.to_vec();
+                w.sort();
+                w[(k - 1) / 2]
+            })
+            .collect();
+        assert_eq!(sliding_window_median(&nums, k), Some(expected), "k={k}");
+    }
+}
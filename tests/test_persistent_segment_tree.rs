@@ -0,0 +1,156 @@
+use code::data_structs::monoid::{Min, Sum};
+use code::data_structs::persistent_segment_tree::{PersistentSegmentTree, RangeOrderStatistics};
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use std::fs::read_to_string;
+use std::path::PathBuf;
+
+/* ---------- deterministic unit tests ---------- */
+
+#[test]
+fn versions_are_preserved() {
+    let mut st: PersistentSegmentTree<Sum<i64>> = PersistentSegmentTree::build(&[1, 2, 3, 4]);
+    let v1 = st.set(0, 1, 10); // [1,10,3,4]
+    let v2 = st.set(v1, 3, 0); // [1,10,3,0]
+    let v3 = st.set(0, 0, -1); // branch from version 0: [-1,2,3,4]
+
+    assert_eq!(st.versions(), 4);
+    assert_eq!(st.query(0, 0, 3), 10);
+    assert_eq!(st.query(v1, 0, 3), 18);
+    assert_eq!(st.query(v2, 0, 3), 14);
+    assert_eq!(st.query(v3, 0, 3), 8);
+    assert_eq!(st.get(v2, 1), 10);
+    assert_eq!(st.get(v3, 1), 2);
+}
+
+#[test]
+fn wrap_around_query() {
+    let mut st: PersistentSegmentTree<Sum<i64>> = PersistentSegmentTree::build(&[7, 1, 9, 2, 3]);
+    let v1 = st.set(0, 0, 0); // [0,1,9,2,3]
+    assert_eq!(st.query(0, 3, 1), 13); // indices 3,4,0,1
+    assert_eq!(st.query(v1, 3, 1), 6);
+    assert_eq!(st.query(v1, 4, 0), 3);
+}
+
+#[test]
+fn kth_smallest_and_counts() {
+    let a = [5, 1, 4, 1, 3, 9, 2];
+    let os = RangeOrderStatistics::new(&a);
+    assert_eq!(os.len(), a.len());
+    assert_eq!(os.kth_smallest(0, 6, 0), Some(1));
+    assert_eq!(os.kth_smallest(0, 6, 1), Some(1));
+    assert_eq!(os.kth_smallest(0, 6, 6), Some(9));
+    assert_eq!(os.kth_smallest(2, 5, 1), Some(3));
+    assert_eq!(os.kth_smallest(2, 5, 4), None);
+    assert_eq!(os.count_le(0, 6, 3), 4);
+    assert_eq!(os.count_le(4, 6, 0), 0);
+    assert_eq!(os.count_range(0, 4, 2, 5), 3);
+    assert_eq!(os.count_range(0, 4, 6, 8), 0);
+}
+
+/* ---------- property‑based randomized test ---------- */
+
+#[test]
+fn random_versions_against_naive() {
+    const N: usize = 23;
+    const OPS: usize = 300;
+    let mut rng = StdRng::seed_from_u64(42);
+
+    let base: Vec<i64> = (0..N).map(|_| rng.random_range(-50..50)).collect();
+    let mut st: PersistentSegmentTree<Min<i64>> = PersistentSegmentTree::build(&base);
+    let mut history = vec![base];
+
+    for _ in 0..OPS {
+        let version = rng.random_range(0..history.len());
+        if rng.random_bool(0.5) {
+            let (i, v) = (rng.random_range(0..N), rng.random_range(-50..50));
+            assert_eq!(st.set(version, i, v), history.len());
+            let mut next = history[version].clone();
+            next[i] = v;
+            history.push(next);
+        } else {
+            let (l, r) = (rng.random_range(0..N), rng.random_range(0..N));
+            let a = &history[version];
+            let expected = if l <= r {
+                *a[l..=r].iter().min().unwrap()
+            } else {
+                *a[l..].iter().chain(&a[..=r]).min().unwrap()
+            };
+            assert_eq!(st.query(version, l, r), expected);
+        }
+    }
+}
+
+#[test]
+fn random_order_statistics_against_naive() {
+    const N: usize = 40;
+    let mut rng = StdRng::seed_from_u64(42);
+    let a: Vec<i32> = (0..N).map(|_| rng.random_range(-10..10)).collect();
+    let os = RangeOrderStatistics::new(&a);
+
+    for l in 0..N {
+        for r in l..N {
+            let mut sorted = a[l..=r].to_vec();
+            sorted.sort();
+            for (k, &v) in sorted.iter().enumerate() {
+                assert_eq!(os.kth_smallest(l, r, k), Some(v));
+            }
+            assert_eq!(os.kth_smallest(l, r, sorted.len()), None);
+
+            let (lo, hi) = (rng.random_range(-12..12), rng.random_range(-12..12));
+            let expected = sorted.iter().filter(|&&v| lo <= v && v <= hi).count();
+            assert_eq!(os.count_range(l, r, lo, hi), expected);
+            assert_eq!(
+                os.count_le(l, r, hi),
+                sorted.iter().filter(|&&v| v <= hi).count()
+            );
+        }
+    }
+}
+
+/* ---------- hands_on_2 is_there test suite ---------- */
+
+///
+/// Answers `IsThere(i,j,k)`: is there a point in `[i,j]` covered by exactly `k` segments.
+///
+/// The coverage of each point is computed with a difference array, then every query is a
+/// `count_range(i, j, k, k)` on the coverage.
+fn is_there(input: &str) -> Vec<bool> {
+    let mut lines = input.lines();
+    let mut header = lines.next().unwrap().split_whitespace();
+    let n: usize = header.next().unwrap().parse().unwrap();
+    let parse = |line: &str| -> Vec<usize> {
+        line.split_whitespace()
+            .map(|s| s.parse().unwrap())
+            .collect()
+    };
+
+    let mut coverage = vec![0i64; n + 1];
+    for line in lines.by_ref().take(n) {
+        let s = parse(line);
+        coverage[s[0]] += 1;
+        coverage[s[1] + 1] -= 1;
+    }
+    for i in 1..n {
+        coverage[i] += coverage[i - 1];
+    }
+    coverage.truncate(n);
+
+    let os = RangeOrderStatistics::new(&coverage);
+    lines
+        .map(|line| {
+            let q = parse(line);
+            os.count_range(q[0], q[1], q[2] as i64, q[2] as i64) > 0
+        })
+        .collect()
+}
+
+#[test]
+fn test_io_is_there() {
+    let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("hands_on_2/test_is_there");
+    for i in 0..=7 {
+        let input = read_to_string(dir.join(format!("input{i}.txt"))).unwrap();
+        let output = read_to_string(dir.join(format!("output{i}.txt"))).unwrap();
+        let expected: Vec<bool> = output.lines().map(|l| l.trim() == "1").collect();
+        assert_eq!(is_there(&input), expected, "input{i}.txt");
+    }
+}
@@ -10,3 +10,35 @@ fn test_codeforces() {
     let expected = vec![1, 0, 0];
     test_case!(rmq, (&a, &q), expected);
 }
+
+#[test]
+fn test_random_against_naive() {
+    use rand::{Rng, SeedableRng, rngs::StdRng};
+
+    let mut rng = StdRng::seed_from_u64(7);
+    for n in 1..12 {
+        let mut ring: Vec<i64> = (0..n).map(|_| rng.random_range(-20..20)).collect();
+        let queries: Vec<(usize, usize, Option<i64>)> = (0..60)
+            .map(|_| {
+                let (l, r) = (rng.random_range(0..n), rng.random_range(0..n));
+                let v = rng.random_bool(0.5).then(|| rng.random_range(-10..10));
+                (l, r, v)
+            })
+            .collect();
+
+        let res = rmq(&ring, &queries);
+        let mut expected = vec![];
+        for &(l, r, q) in &queries {
+            let idx: Vec<usize> = if l <= r {
+                (l..=r).collect()
+            } else {
+                (l..n).chain(0..=r).collect()
+            };
+            match q {
+                Some(v) => idx.iter().for_each(|&i| ring[i] += v),
+                None => expected.push(idx.iter().map(|&i| ring[i]).min().unwrap()),
+            }
+        }
+        assert_eq!(res, expected);
+    }
+}
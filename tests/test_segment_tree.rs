@@ -1,44 +1,55 @@
-use code::data_structs::segment_tree::SegmentTreeSum;
+use code::data_structs::monoid::{Gcd, Max, Min, Monoid, Sum, Xor};
 use code::data_structs::segment_tree::SegmentTree;
+use code::data_structs::segment_tree::SegmentTreeSum;
 use rand::{Rng, SeedableRng, rngs::StdRng};
 
 /* ---------- helpers ---------- */
 
 /// Slow but simple reference implementation for sanity‑checking.
-fn naive_min_after_add(
-    mut data: Vec<i64>,
-    ops: &[(usize, usize, i64)],
-    ql: usize,
-    qr: usize,
-) -> i64 {
-    for &(l, r, v) in ops {
-        if l <= r {
-            for i in l..=r {
-                data[i] += v;
-            }
-        } else {
-            for i in l..data.len() {
-                data[i] += v;
-            }
-            for i in 0..=r {
-                data[i] += v;
-            }
-        }
-    }
-    let slice = if ql <= qr {
-        &data[ql..=qr]
+fn naive_query<M: Monoid>(data: &[M::Value], ql: usize, qr: usize) -> M::Value {
+    let fold = |acc: M::Value, v: &M::Value| M::combine(&acc, v);
+    if ql <= qr {
+        data[ql..=qr].iter().fold(M::identity(), fold)
     } else {
         // wrap‑around query
-        let min1 = data[ql..].iter().min().unwrap();
-        let min2 = data[..=qr].iter().min().unwrap();
-        return *min1.min(min2);
-    };
-    *slice.iter().min().unwrap()
+        let tail = data[ql..].iter().fold(M::identity(), fold);
+        data[..=qr].iter().fold(tail, fold)
+    }
 }
 
-fn expect_tree_matches_array(tree: &mut SegmentTree, arr: &[i64]) {
-    for i in 0..arr.len() {
-        assert_eq!(tree.min_range(i, i), arr[i], "leaf mismatch at index {i}");
+fn expect_tree_matches_array(tree: &SegmentTree<Min<i64>>, arr: &[i64]) {
+    for (i, &v) in arr.iter().enumerate() {
+        assert_eq!(tree.get(i), v, "leaf mismatch at index {i}");
+    }
+}
+
+/// Random point updates and range queries checked against the naive fold
+fn random_operations<M>(rng: &mut StdRng, mut gen_value: impl FnMut(&mut StdRng) -> M::Value)
+where
+    M: Monoid,
+    M::Value: PartialEq + std::fmt::Debug,
+{
+    const N: usize = 33;
+    const OPS: usize = 300;
+
+    let mut data: Vec<M::Value> = (0..N).map(|_| gen_value(rng)).collect();
+    let mut st: SegmentTree<M> = SegmentTree::build(&data);
+
+    for _ in 0..OPS {
+        if rng.random_bool(0.4) {
+            let i = rng.random_range(0..N);
+            let v = gen_value(rng);
+            st.set(i, v.clone());
+            data[i] = v;
+        } else {
+            let l = rng.random_range(0..N);
+            let r = rng.random_range(0..N);
+            assert_eq!(
+                st.query(l, r),
+                naive_query::<M>(&data, l, r),
+                "query=({l},{r})"
+            );
+        }
     }
 }
 
@@ -47,83 +58,102 @@ fn expect_tree_matches_array(tree: &mut SegmentTree, arr: &[i64]) {
 #[test]
 fn build_and_point_queries() {
     let data = vec![3, 1, 4, 1, 5, 9, 2];
-    let mut st = SegmentTree::build(&data);
+    let st: SegmentTree<Min<i64>> = SegmentTree::build(&data);
     assert_eq!(st.len(), data.len());
-    expect_tree_matches_array(&mut st, &data);
+    expect_tree_matches_array(&st, &data);
 
     // global min should match std::iter::min
-    assert_eq!(st.min_range(0, data.len() - 1), *data.iter().min().unwrap());
+    assert_eq!(st.query(0, data.len() - 1), *data.iter().min().unwrap());
 }
 
 #[test]
-fn range_add_then_query() {
-    let mut st = SegmentTree::build(&[0, 0, 0, 0]);
-    st.add_range(1, 3, 10); // [0,10,10,10]
-    st.add_range(0, 0, -5); // [-5,10,10,10]
-    assert_eq!(st.min_range(0, 3), -5);
-    assert_eq!(st.min_range(1, 2), 10);
-    expect_tree_matches_array(&mut st, &[-5, 10, 10, 10]);
+fn build_empty() {
+    let st: SegmentTree<Sum<i64>> = SegmentTree::build(&[]);
+    assert!(st.is_empty());
 }
 
 #[test]
-fn wrap_around_update_and_query() {
-    let mut st = SegmentTree::build(&[7, 7, 7, 7, 7]);
-    st.add_range(3, 1, -2); // affects indices 3,4,0,1
-    assert_eq!(st.min_range(4, 2), 5); // wrap query too
-    expect_tree_matches_array(&mut st, &[5, 5, 7, 5, 5]);
+fn set_then_query() {
+    let mut st: SegmentTree<Min<i64>> = SegmentTree::build(&[0, 0, 0, 0]);
+    st.set(1, 10);
+    st.set(0, -5);
+    assert_eq!(st.query(0, 3), -5);
+    assert_eq!(st.query(1, 2), 0);
+    expect_tree_matches_array(&st, &[-5, 10, 0, 0]);
 }
 
 #[test]
-fn overlapping_updates_idempotent() {
-    let mut st = SegmentTree::build(&[1, 1, 1, 1]);
-    st.add_range(0, 3, 3);
-    st.add_range(1, 2, -1);
-    assert_eq!(st.min_range(0, 3), 3); // [4,3,3,4]
-    expect_tree_matches_array(&mut st, &[4, 3, 3, 4]);
+fn wrap_around_query() {
+    let st: SegmentTree<Max<i32>> = SegmentTree::build(&[7, 1, 9, 2, 3]);
+    assert_eq!(st.query(3, 1), 7); // indices 3,4,0,1
+    assert_eq!(st.query(4, 0), 7);
+    assert_eq!(st.query(3, 2), 9);
 }
 
-/* ---------- property‑based randomized test ---------- */
+#[test]
+fn sum_on_partial_overlap() {
+    let st: SegmentTree<Sum<i64>> = SegmentTree::build(&[5, 1, 4, 2, 8, 3]);
+    assert_eq!(st.query(1, 4), 15);
+    assert_eq!(st.query(2, 2), 4);
+    assert_eq!(st.query(4, 1), 17);
+}
 
 #[test]
-fn random_operations_against_naive() {
-    const N: usize = 32;
-    const OPS: usize = 200;
-    let seed = 42; // keep deterministic; change for new scenarios
-    let mut rng = StdRng::seed_from_u64(seed);
+fn gcd_and_xor() {
+    let g: SegmentTree<Gcd<u64>> = SegmentTree::build(&[12, 18, 24, 7, 14]);
+    assert_eq!(g.query(0, 2), 6);
+    assert_eq!(g.query(2, 3), 1);
+    assert_eq!(g.query(3, 4), 7);
+
+    let x: SegmentTree<Xor<u32>> = SegmentTree::build(&[1, 2, 4, 8]);
+    assert_eq!(x.query(0, 3), 15);
+    assert_eq!(x.query(1, 2), 6);
+}
 
-    let base: Vec<i64> = (0..N).map(|_| rng.random_range(-50..50)).collect();
-    let mut st = SegmentTree::build(&base);
+/// A non commutative monoid: the tree must combine left to right
+struct Concat;
 
-    let mut log_ops: Vec<(usize, usize, i64)> = Vec::new();
+impl Monoid for Concat {
+    type Value = String;
 
-    for _ in 0..OPS {
-        if rng.random_bool(0.6) {
-            // range add
-            let l = rng.random_range(0..N);
-            let r = rng.random_range(0..N);
-            let v = rng.random_range(-20..20);
-            st.add_range(l, r, v);
-            log_ops.push((l, r, v));
-        } else {
-            // range min query – check against naïve every time
-            let l = rng.random_range(0..N);
-            let r = rng.random_range(0..N);
-            let expected = naive_min_after_add(base.clone(), &log_ops, l, r);
-            let got = st.min_range(l, r);
-            assert_eq!(
-                got, expected,
-                "mismatch after ops={log_ops:?}, query=({l},{r})"
-            );
-        }
+    fn identity() -> String {
+        String::new()
+    }
+
+    fn combine(a: &String, b: &String) -> String {
+        format!("{a}{b}")
     }
 }
 
+#[test]
+fn custom_monoid_keeps_order() {
+    let data: Vec<String> = "abcde".chars().map(String::from).collect();
+    let mut st: SegmentTree<Concat> = SegmentTree::build(&data);
+    assert_eq!(st.query(1, 3), "bcd");
+    assert_eq!(st.query(3, 1), "deab");
+    st.set(2, "X".to_string());
+    assert_eq!(st.query(0, 4), "abXde");
+}
+
+/* ---------- property‑based randomized test ---------- */
+
+#[test]
+fn random_operations_against_naive() {
+    let seed = 42; // keep deterministic; change for new scenarios
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    random_operations::<Min<i64>>(&mut rng, |r| r.random_range(-50..50));
+    random_operations::<Max<i64>>(&mut rng, |r| r.random_range(-50..50));
+    random_operations::<Sum<i64>>(&mut rng, |r| r.random_range(-50..50));
+    random_operations::<Gcd<u64>>(&mut rng, |r| r.random_range(0..60));
+    random_operations::<Xor<u32>>(&mut rng, |r| r.random_range(0..1024));
+}
 
 #[test]
 fn test_range_sum() {
-    let base_slice = [0,0,0,0,0];
+    let base_slice = [0, 0, 0, 0, 0];
     let mut segment = SegmentTreeSum::build(&base_slice);
     segment.range_add(0, 2, 1);
-    println!("{:?}",segment.tree);
-    assert_eq!(segment.range_sum(0, 2),3); 
-}
\ No newline at end of file
+    println!("{:?}", segment.tree);
+    assert_eq!(segment.range_sum(0, 2), 3);
+}
@@ -0,0 +1,94 @@
+use code::data_structs::segment_tree_beats::SegmentTreeBeats;
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+/* ---------- helpers ---------- */
+
+/// Indices covered by a (possibly circular) range
+fn range_indices(n: usize, l: usize, r: usize) -> Vec<usize> {
+    if l <= r {
+        (l..=r).collect()
+    } else {
+        (l..n).chain(0..=r).collect()
+    }
+}
+
+fn expect_tree_matches_array(tree: &mut SegmentTreeBeats, arr: &[i64]) {
+    for (i, &v) in arr.iter().enumerate() {
+        assert_eq!(tree.get(i), v, "leaf mismatch at index {i}");
+    }
+}
+
+/* ---------- deterministic unit tests ---------- */
+
+#[test]
+fn build_and_queries() {
+    let data = vec![3, 1, 4, 1, 5, 9, 2];
+    let mut st = SegmentTreeBeats::build(&data);
+    assert_eq!(st.len(), data.len());
+    expect_tree_matches_array(&mut st, &data);
+    assert_eq!(st.sum(0, 6), 25);
+    assert_eq!(st.max(0, 6), 9);
+    assert_eq!(st.min(2, 4), 1);
+    assert_eq!(st.sum(5, 1), 15); // wrap: 9 + 2 + 3 + 1
+}
+
+#[test]
+fn chmin_then_sum() {
+    let mut st = SegmentTreeBeats::build(&[5, 3, 8, 7]);
+    st.chmin(1, 2, 4); // clamp 3 and 8 to min(x,4)
+    expect_tree_matches_array(&mut st, &[5, 3, 4, 7]);
+    assert_eq!(st.sum(0, 3), 19);
+    assert_eq!(st.max(0, 3), 7);
+    st.chmin(0, 3, 4);
+    assert_eq!(st.sum(0, 3), 15);
+}
+
+#[test]
+fn chmax_add_and_chmin() {
+    let mut st = SegmentTreeBeats::build(&[1, 6, 2, 8, 3]);
+    st.chmax(0, 4, 3); // [3,6,3,8,3]
+    assert_eq!(st.sum(0, 4), 23);
+    st.add(1, 3, -2); // [3,4,1,6,3]
+    assert_eq!(st.min(0, 4), 1);
+    st.chmin(3, 0, 2); // indices 3,4,0 -> [2,4,1,2,2]
+    expect_tree_matches_array(&mut st, &[2, 4, 1, 2, 2]);
+    assert_eq!(st.sum(0, 4), 11);
+}
+
+/* ---------- property‑based randomized test ---------- */
+
+#[test]
+fn random_operations_against_naive() {
+    const N: usize = 37;
+    const OPS: usize = 2000;
+    let seed = 42; // keep deterministic; change for new scenarios
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    let mut data: Vec<i64> = (0..N).map(|_| rng.random_range(-50..50)).collect();
+    let mut st = SegmentTreeBeats::build(&data);
+
+    for _ in 0..OPS {
+        let l = rng.random_range(0..N);
+        let r = rng.random_range(0..N);
+        let x = rng.random_range(-50..50);
+        let idx = range_indices(N, l, r);
+        match rng.random_range(0..6) {
+            0 => {
+                st.chmin(l, r, x);
+                idx.iter().for_each(|&i| data[i] = data[i].min(x));
+            }
+            1 => {
+                st.chmax(l, r, x);
+                idx.iter().for_each(|&i| data[i] = data[i].max(x));
+            }
+            2 => {
+                st.add(l, r, x / 5);
+                idx.iter().for_each(|&i| data[i] += x / 5);
+            }
+            3 => assert_eq!(st.sum(l, r), idx.iter().map(|&i| data[i]).sum::<i64>()),
+            4 => assert_eq!(st.max(l, r), idx.iter().map(|&i| data[i]).max().unwrap()),
+            _ => assert_eq!(st.min(l, r), idx.iter().map(|&i| data[i]).min().unwrap()),
+        }
+    }
+    expect_tree_matches_array(&mut st, &data);
+}
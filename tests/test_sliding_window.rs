@@ -0,0 +1,120 @@
+use code::data_structs::monoid::{Gcd, Max, Monoid, Sum};
+use code::data_structs::sliding_window::{SlidingWindow, fold_windows};
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use std::collections::VecDeque;
+
+/* ---------- helpers ---------- */
+
+const MOD: u64 = 1_000_000_007;
+
+/// 2x2 matrix product modulo `MOD` (associative, not commutative)
+struct MatMul;
+
+impl Monoid for MatMul {
+    type Value = [[u64; 2]; 2];
+
+    fn identity() -> Self::Value {
+        [[1, 0], [0, 1]]
+    }
+
+    fn combine(a: &Self::Value, b: &Self::Value) -> Self::Value {
+        let mut res = [[0; 2]; 2];
+        for (i, row) in res.iter_mut().enumerate() {
+            for (j, cell) in row.iter_mut().enumerate() {
+                *cell = (a[i][0] * b[0][j] + a[i][1] * b[1][j]) % MOD;
+            }
+        }
+        res
+    }
+}
+
+fn naive_fold<M: Monoid>(values: &VecDeque<M::Value>) -> M::Value {
+    values
+        .iter()
+        .fold(M::identity(), |acc, v| M::combine(&acc, v))
+}
+
+/* ---------- deterministic unit tests ---------- */
+
+#[test]
+fn push_pop_and_fold() {
+    let mut w = SlidingWindow::<Sum<i64>>::new();
+    assert!(w.is_empty());
+    assert_eq!(w.fold(), 0);
+    assert_eq!(w.pop_front(), None);
+
+    w.extend([1, 2, 3]);
+    assert_eq!(w.len(), 3);
+    assert_eq!(w.fold(), 6);
+    assert_eq!(w.pop_front(), Some(1));
+    w.push(10);
+    assert_eq!(w.fold(), 15);
+    assert_eq!(w.pop_front(), Some(2));
+    assert_eq!(w.pop_front(), Some(3));
+    assert_eq!(w.fold(), 10);
+    assert_eq!(w.pop_front(), Some(10));
+    assert!(w.is_empty());
+}
+
+#[test]
+fn gcd_windows() {
+    let a = [12u64, 18, 24, 9, 27, 81, 5];
+    let res: Vec<u64> = fold_windows::<Gcd<u64>, _>(a, 3).collect();
+    assert_eq!(res, vec![6, 3, 3, 9, 1]);
+    assert_eq!(fold_windows::<Gcd<u64>, _>(a, 0).count(), 0);
+    assert_eq!(fold_windows::<Gcd<u64>, _>(a, 8).count(), 0);
+}
+
+#[test]
+fn matrix_product_keeps_order() {
+    //fibonacci matrix and a shear: the product depends on the order
+    let f = [[1, 1], [1, 0]];
+    let s = [[1, 2], [0, 1]];
+    let w: SlidingWindow<MatMul> = [f, s].into_iter().collect();
+    assert_eq!(w.fold(), MatMul::combine(&f, &s));
+    assert_ne!(w.fold(), MatMul::combine(&s, &f));
+}
+
+#[test]
+fn windows_over_unbounded_stream() {
+    let stream = (1..).map(|i: i64| (i * 37) % 11);
+    let first: Vec<i64> = fold_windows::<Max<i64>, _>(stream, 4).take(5).collect();
+    //stream starts with 4, 8, 1, 5, 9, 2, 6, 10
+    assert_eq!(first, vec![8, 9, 9, 9, 10]);
+}
+
+/* ---------- property‑based randomized test ---------- */
+
+#[test]
+fn random_operations_against_naive() {
+    let mut rng = StdRng::seed_from_u64(42);
+    let mut w = SlidingWindow::<MatMul>::new();
+    let mut naive = VecDeque::new();
+    for _ in 0..3000 {
+        if naive.is_empty() || rng.random_bool(0.55) {
+            let m = [
+                [rng.random_range(0..MOD), rng.random_range(0..MOD)],
+                [rng.random_range(0..MOD), rng.random_range(0..MOD)],
+            ];
+            w.push(m);
+            naive.push_back(m);
+        } else {
+            assert_eq!(w.pop_front(), naive.pop_front());
+        }
+        assert_eq!(w.len(), naive.len());
+        assert_eq!(w.fold(), naive_fold::<MatMul>(&naive));
+    }
+}
+
+#[test]
+fn random_windows_against_slices() {
+    let mut rng = StdRng::seed_from_u64(7);
+    for _ in 0..200 {
+        let n = rng.random_range(0..60);
+        let a: Vec<i64> = (0..n).map(|_| rng.random_range(-50..50)).collect();
+        let k = rng.random_range(1..n + 2);
+        let expected: Vec<i64> = a.windows(k).map(|w| w.iter().sum()).collect();
+        let res: Vec<i64> = fold_windows::<Sum<i64>, _>(a.iter().copied(), k).collect();
+        assert_eq!(res, expected, "n={n} k={k}");
+    }
+}
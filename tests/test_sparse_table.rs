@@ -0,0 +1,84 @@
+use code::data_structs::sparse_table::SparseTable;
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+/* ---------- helpers ---------- */
+
+/// Indices covered by a (possibly circular) range
+fn range_indices(n: usize, l: usize, r: usize) -> Vec<usize> {
+    if l <= r {
+        (l..=r).collect()
+    } else {
+        (l..n).chain(0..=r).collect()
+    }
+}
+
+fn gcd(a: &u64, b: &u64) -> u64 {
+    if *b == 0 { *a } else { gcd(b, &(a % b)) }
+}
+
+///
+/// Random (possibly circular) queries checked against a fold of the covered items
+fn random_queries<F: Fn(&u64, &u64) -> u64 + Copy>(rng: &mut StdRng, op: F) {
+    for n in [1, 2, 3, 8, 13, 64, 100] {
+        let data: Vec<u64> = (0..n).map(|_| rng.random_range(0..1000)).collect();
+        let st = SparseTable::new(&data, op);
+        for _ in 0..200 {
+            let l = rng.random_range(0..n);
+            let r = rng.random_range(0..n);
+            let idx = range_indices(n, l, r);
+            let expected = idx[1..]
+                .iter()
+                .fold(data[idx[0]], |acc, &i| op(&acc, &data[i]));
+            assert_eq!(st.query(l, r), expected, "n={n} query=({l},{r})");
+        }
+    }
+}
+
+/* ---------- deterministic unit tests ---------- */
+
+#[test]
+fn min_and_max_queries() {
+    let data = vec![3, 1, 4, 1, 5, 9, 2, 6];
+    let min = SparseTable::new(&data, |a: &i32, b: &i32| *a.min(b));
+    let max = SparseTable::new(&data, |a: &i32, b: &i32| *a.max(b));
+    assert_eq!(min.len(), 8);
+    assert_eq!(min.query(0, 7), 1);
+    assert_eq!(min.query(4, 7), 2);
+    assert_eq!(max.query(0, 4), 5);
+    assert_eq!(max.query(3, 3), 1);
+}
+
+#[test]
+fn wrap_around_queries() {
+    let data = vec![5, 8, 1, 7, 3];
+    let max = SparseTable::new(&data, |a: &i32, b: &i32| *a.max(b));
+    assert_eq!(max.query(3, 0), 7); // 7, 3, 5
+    assert_eq!(max.query(4, 1), 8); // 3, 5, 8
+    let min = SparseTable::new(&data, |a: &i32, b: &i32| *a.min(b));
+    assert_eq!(min.query(3, 1), 3);
+}
+
+#[test]
+fn empty_input_does_not_panic() {
+    let st = SparseTable::new(&[], |a: &i32, b: &i32| *a.min(b));
+    assert!(st.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "Range not supported")]
+fn out_of_bounds_query() {
+    let st = SparseTable::new(&[1, 2, 3], |a: &i32, b: &i32| *a.min(b));
+    st.query(0, 3);
+}
+
+/* ---------- property‑based randomized test ---------- */
+
+#[test]
+fn random_queries_against_naive() {
+    let mut rng = StdRng::seed_from_u64(42);
+    random_queries(&mut rng, |a, b| *a.min(b));
+    random_queries(&mut rng, |a, b| *a.max(b));
+    random_queries(&mut rng, gcd);
+    random_queries(&mut rng, |a, b| a & b);
+    random_queries(&mut rng, |a, b| a | b);
+}
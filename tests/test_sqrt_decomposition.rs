@@ -0,0 +1,120 @@
+use code::data_structs::action::Add;
+use code::data_structs::lazy_segment_tree::LazySegmentTree;
+use code::data_structs::monoid::Sum;
+use code::data_structs::sqrt_decomposition::SqrtDecomposition;
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+/* ---------- helpers ---------- */
+
+/// Indices covered by a (possibly circular) range
+fn range_indices(n: usize, l: usize, r: usize) -> Vec<usize> {
+    if l <= r {
+        (l..=r).collect()
+    } else {
+        (l..n).chain(0..=r).collect()
+    }
+}
+
+/* ---------- deterministic unit tests ---------- */
+
+#[test]
+fn build_and_queries() {
+    let data = vec![3, 1, 4, 1, 5, 9, 2, 6, 5, 3];
+    let sd = SqrtDecomposition::build(&data);
+    assert_eq!(sd.len(), 10);
+    assert_eq!(sd.block_size(), 4);
+    assert_eq!(sd.sum(0, 9), 39);
+    assert_eq!(sd.min(4, 7), 2);
+    assert_eq!(sd.max(0, 4), 5);
+    assert_eq!(sd.sum(8, 1), 12); // wrap: 5 + 3 + 3 + 1
+    assert_eq!(sd.get(5), 9);
+}
+
+#[test]
+fn add_then_assign() {
+    let mut sd = SqrtDecomposition::with_block_size(&[0i64; 7], 3);
+    sd.add(1, 5, 2); // [0,2,2,2,2,2,0]
+    sd.assign(2, 3, 7); // [0,2,7,7,2,2,0]
+    sd.add(0, 6, -1); // [-1,1,6,6,1,1,-1]
+    assert_eq!(sd.sum(0, 6), 13);
+    assert_eq!(sd.min(1, 5), 1);
+    assert_eq!(sd.max(3, 4), 6);
+    sd.assign(5, 1, 4); // wrap: [4,4,6,6,1,4,4]
+    assert_eq!(sd.sum(0, 6), 29);
+    assert_eq!(sd.get(4), 1);
+}
+
+#[test]
+fn empty_input_does_not_panic() {
+    let sd = SqrtDecomposition::<i32>::build(&[]);
+    assert!(sd.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "Block size must be positive")]
+fn zero_block_size() {
+    SqrtDecomposition::with_block_size(&[1, 2, 3], 0);
+}
+
+/* ---------- property‑based randomized test ---------- */
+
+#[test]
+fn random_operations_against_naive() {
+    let mut rng = StdRng::seed_from_u64(42);
+    for (n, block_size) in [
+        (1, 1),
+        (10, 1),
+        (10, 3),
+        (29, 5),
+        (29, 29),
+        (29, 100),
+        (64, 8),
+    ] {
+        let mut data: Vec<i64> = (0..n).map(|_| rng.random_range(-50..50)).collect();
+        let mut sd = SqrtDecomposition::with_block_size(&data, block_size);
+        for _ in 0..400 {
+            let l = rng.random_range(0..n);
+            let r = rng.random_range(0..n);
+            let idx = range_indices(n, l, r);
+            match rng.random_range(0..10) {
+                0..3 => {
+                    let v = rng.random_range(-20..20);
+                    sd.add(l, r, v);
+                    idx.iter().for_each(|&i| data[i] += v);
+                }
+                3..5 => {
+                    let v = rng.random_range(-50..50);
+                    sd.assign(l, r, v);
+                    idx.iter().for_each(|&i| data[i] = v);
+                }
+                _ => {
+                    let values: Vec<i64> = idx.iter().map(|&i| data[i]).collect();
+                    let msg = format!("n={n} b={block_size} query=({l},{r})");
+                    assert_eq!(sd.sum(l, r), values.iter().sum::<i64>(), "{msg}");
+                    assert_eq!(sd.min(l, r), *values.iter().min().unwrap(), "{msg}");
+                    assert_eq!(sd.max(l, r), *values.iter().max().unwrap(), "{msg}");
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn agrees_with_lazy_segment_tree() {
+    const N: usize = 1000;
+    let mut rng = StdRng::seed_from_u64(7);
+    let data: Vec<i64> = (0..N).map(|_| rng.random_range(-1000..1000)).collect();
+    let mut sd = SqrtDecomposition::build(&data);
+    let mut st: LazySegmentTree<Sum<i64>, Add<i64>> = LazySegmentTree::build(&data);
+    for _ in 0..2000 {
+        let l = rng.random_range(0..N);
+        let r = rng.random_range(0..N);
+        if rng.random_bool(0.5) {
+            let v = rng.random_range(-100..100);
+            sd.add(l, r, v);
+            st.apply(l, r, v);
+        } else {
+            assert_eq!(sd.sum(l, r), st.query(l, r), "query=({l},{r})");
+        }
+    }
+}
@@ -1,10 +1,10 @@
 #![allow(unused_imports)]
-use code::optional::set2::trap_water::{trap_water, trap_water_2_pass};
+use code::optional::set2::trap_water::{trap_water, trap_water_2_pass, trap_water_stack};
 use code::test_util::TestCase;
 
 type TestC<'a> = TestCase<&'a [u32], u32>;
 
-const TO_TEST: [fn(&[u32]) -> u32; 2] = [trap_water_2_pass, trap_water];
+const TO_TEST: [fn(&[u32]) -> u32; 3] = [trap_water_2_pass, trap_water, trap_water_stack];
 
 #[test]
 fn test_empty() {
@@ -0,0 +1,88 @@
+use code::data_structs::binary_tree::{BinaryTree, Render, max_path_sum_with_path, to_dot};
+use code::data_structs::tree::TreeNode;
+use code::{tree, tree_leaf, tree_left, tree_right};
+use hands_on_1::Tree;
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+/* ---------- helpers ---------- */
+
+fn random_tree(rng: &mut StdRng, n: usize) -> TreeNode<i32> {
+    let mut t = Tree::with_root(rng.random_range(-9..10));
+    while t.nodes.len() < n {
+        let parent = rng.random_range(0..t.nodes.len());
+        let _ = t.add_node(parent, rng.random_range(-9..10), rng.random_bool(0.5));
+    }
+    TreeNode::try_from(t).unwrap()
+}
+
+/* ---------- deterministic unit tests ---------- */
+
+#[test]
+fn display_layout() {
+    let t = tree!(
+        1,
+        tree!(2, tree_leaf!(4), tree_leaf!(5)),
+        tree_left!(3, tree_leaf!(6))
+    );
+    let expected = ["┌── 3", "│   └── 6", "1", "│   ┌── 5", "└── 2", "    └── 4"];
+    assert_eq!(t.to_string(), expected.join("\n") + "\n");
+    assert_eq!(tree_leaf!("x").to_string(), "x\n");
+
+    let t = tree_right!(1, tree_left!(2, tree_right!(3, tree_leaf!(4))));
+    let expected = ["┌── 2", "│   │   ┌── 4", "│   └── 3", "1"];
+    assert_eq!(t.to_string(), expected.join("\n") + "\n");
+}
+
+#[test]
+fn dot_highlights_path() {
+    let t = tree!(
+        10,
+        tree!(2, tree_leaf!(20), tree_leaf!(1)),
+        tree_right!(10, tree!(-25, tree_leaf!(3), tree_leaf!(4)))
+    );
+    //preorder: 10 2 20 1 10 -25 3 4, the max path is 20 -> 2 -> 1
+    let (_, path) = max_path_sum_with_path(&t).unwrap();
+    let dot = t.to_dot(&path);
+    assert!(dot.contains("n2 [label=\"20\", color=red, penwidth=2];"));
+    assert!(dot.contains("n0 [label=\"10\"];"));
+    assert!(dot.contains("n1 -> n3 [color=red, penwidth=2];"));
+    assert!(dot.contains("n0 -> n1;"));
+    assert!(dot.contains("n4l [style=invis];"));
+    assert_eq!(dot.matches("color=red").count(), 3 + 2);
+
+    //a path given by hand: 20 -> 2 -> 10 -> 10 -> -25 -> 4
+    let nodes: Vec<_> = t.preorder().collect();
+    let dot = t.to_dot(&[2, 1, 0, 4, 5, 7].map(|i| nodes[i]));
+    assert!(dot.contains("n3 [label=\"1\"];"));
+    assert!(dot.contains("n4 -> n5 [color=red, penwidth=2];"));
+    assert!(dot.contains("n5 -> n6;"));
+    assert_eq!(dot.matches("color=red").count(), 6 + 5);
+    let plain = t.to_dot(&[]);
+    assert!(!plain.contains("color=red"));
+}
+
+/* ---------- property‑based randomized test ---------- */
+
+#[test]
+fn same_output_for_both_representations() {
+    let mut rng = StdRng::seed_from_u64(42);
+    for _ in 0..200 {
+        let n = rng.random_range(1..40);
+        let t = random_tree(&mut rng, n);
+        let positions: Vec<usize> = (0..rng.random_range(0..n))
+            .map(|_| rng.random_range(0..n))
+            .collect();
+        let nodes: Vec<_> = t.preorder().collect();
+        let path: Vec<_> = positions.iter().map(|&i| nodes[i]).collect();
+        let (text, dot) = (t.to_string(), t.to_dot(&path));
+        assert_eq!(text.lines().count(), n);
+
+        //the arena is numbered in preorder, so its ids are the positions of the boxed nodes
+        let arena = Tree::from(t);
+        assert_eq!(Render(&arena).to_string(), text);
+        assert_eq!(to_dot(&arena, &positions), dot);
+        //hands_on_1 draws its own trees with the same layout and node names
+        assert_eq!(arena.to_string(), text);
+        assert_eq!(arena.to_dot(&positions), dot);
+    }
+}
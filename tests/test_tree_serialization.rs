@@ -0,0 +1,142 @@
+use code::data_structs::binary_tree::{is_bst, max_path_sum, sum, to_brackets, to_level_order};
+use code::data_structs::tree::TreeNode;
+use code::{tree, tree_leaf, tree_left, tree_right};
+use hands_on_1::Tree;
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use std::fs::read_to_string;
+
+/* ---------- helpers ---------- */
+
+/// Random level order array: the root is always present, children are missing with
+/// probability 1/3
+fn random_level_order(rng: &mut StdRng, n: usize) -> Vec<Option<i32>> {
+    let mut a = vec![Some(rng.random_range(-50..50))];
+    let (mut slots, mut nodes) = (2, 1);
+    while slots > 0 && nodes < n {
+        slots -= 1;
+        if rng.random_bool(0.66) {
+            a.push(Some(rng.random_range(-50..50)));
+            slots += 2;
+            nodes += 1;
+        } else {
+            a.push(None);
+        }
+    }
+    while let Some(None) = a.last() {
+        a.pop();
+    }
+    a
+}
+
+/* ---------- deterministic unit tests ---------- */
+
+#[test]
+fn level_order_round_trip() {
+    let a = [Some(5), Some(4), Some(8), Some(11), None, Some(13), Some(4)];
+    let t = TreeNode::from_level_order(&a).unwrap();
+    assert_eq!(t.val, 5);
+    assert_eq!(t.get_left().unwrap().get_left().unwrap().val, 11);
+    assert!(t.get_left().unwrap().get_right().is_none());
+    assert_eq!(t.to_level_order(), a.to_vec());
+    assert_eq!(sum(&t), Some(45));
+
+    assert!(TreeNode::<i32>::from_level_order(&[]).is_none());
+    assert!(TreeNode::<i32>::from_level_order(&[None]).is_none());
+}
+
+#[test]
+fn brackets_round_trip() {
+    let t = tree!(
+        1,
+        tree!(2, tree_leaf!(4), tree_leaf!(5)),
+        tree_right!(3, tree_left!(6, tree_leaf!(-7)))
+    );
+    let s = t.to_brackets();
+    assert_eq!(s, "1(2(4)(5))(3()(6(-7)))");
+    let parsed: TreeNode<i32> = TreeNode::from_brackets(&s).unwrap();
+    assert_eq!(parsed.to_brackets(), s);
+    assert_eq!(
+        parsed.to_level_order(),
+        vec![
+            Some(1),
+            Some(2),
+            Some(3),
+            Some(4),
+            Some(5),
+            None,
+            Some(6),
+            None,
+            None,
+            None,
+            None,
+            Some(-7)
+        ]
+    );
+}
+
+#[test]
+fn brackets_errors() {
+    let parse = |s: &str| TreeNode::<i32>::from_brackets(s).err();
+    assert_eq!(parse(""), Some("Empty tree"));
+    assert_eq!(parse("1(2"), Some("Unbalanced parentheses"));
+    assert_eq!(parse("1(2)(3)(4)"), Some("A node has at most two children"));
+    assert_eq!(parse("1(a)"), Some("Invalid key"));
+    assert_eq!(parse("1(2)é"), Some("Unexpected key"));
+    assert_eq!(parse("1(ü)"), Some("Invalid key"));
+}
+
+#[test]
+fn same_format_for_both_representations() {
+    let arena: Tree<i32> = Tree::from_brackets("10(2(20)(1))(10()(-25(3)(4)))").unwrap();
+    let boxed = TreeNode::from_level_order(&to_level_order(&arena)).unwrap();
+    assert_eq!(to_brackets(&arena), "10(2(20)(1))(10()(-25(3)(4)))");
+    assert_eq!(boxed.to_brackets(), to_brackets(&arena));
+    assert_eq!(boxed.to_level_order(), to_level_order(&arena));
+    assert_eq!(max_path_sum(&boxed), Some(23));
+}
+
+/* ---------- property‑based randomized test ---------- */
+
+#[test]
+fn random_round_trips() {
+    let mut rng = StdRng::seed_from_u64(42);
+    for _ in 0..300 {
+        let n = rng.random_range(1..50);
+        let a = random_level_order(&mut rng, n);
+        let boxed = TreeNode::from_level_order(&a).unwrap();
+        assert_eq!(boxed.to_level_order(), a);
+
+        let s = boxed.to_brackets();
+        let arena: Tree<i32> = Tree::from_brackets(&s).unwrap();
+        assert_eq!(to_level_order(&arena), a);
+        assert_eq!(to_brackets(&arena), s);
+        //the printers of hands_on_1 agree with the generic ones
+        assert_eq!(arena.to_level_order(), a);
+        assert_eq!(arena.to_brackets(), s);
+        assert_eq!(
+            TreeNode::<i32>::from_brackets(&s).unwrap().to_level_order(),
+            a
+        );
+        assert_eq!(to_brackets(&Tree::from(boxed)), s);
+    }
+}
+
+#[test]
+fn hands_on_1_test_files() {
+    //the files of hands_on_1 checked on TreeNode with the generic algorithms
+    for i in 0..4 {
+        let input = read_to_string(format!("hands_on_1/test_trees/input{i}.txt")).unwrap();
+        let output = read_to_string(format!("hands_on_1/test_trees/output{i}.txt")).unwrap();
+        for (line, expected) in input.lines().skip(1).zip(output.lines()) {
+            let t: TreeNode<i64> = TreeNode::from_brackets(line).unwrap();
+            let expected: Vec<&str> = expected.split_whitespace().collect();
+            assert_eq!(sum(&t).unwrap().to_string(), expected[0]);
+            assert_eq!(is_bst(&t), expected[1] == "1");
+            //hands_on_1 doesn't count a root with a single child as a special node
+            if t.left.is_some() == t.right.is_some() {
+                let path = max_path_sum(&t).map_or("-".to_string(), |s| s.to_string());
+                assert_eq!(path, expected[2], "tree: {line}");
+            }
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use code::data_structs::wavelet_tree::WaveletTree;
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use std::fs::read_to_string;
+use std::path::PathBuf;
+
+/* ---------- deterministic unit tests ---------- */
+
+#[test]
+fn rank_and_kth_smallest() {
+    let a = [5, 1, 4, 1, 5, 9, 2, 6];
+    let wt = WaveletTree::new(&a);
+    assert_eq!(wt.len(), 8);
+    assert_eq!(wt.rank(1, 8), 2);
+    assert_eq!(wt.rank(1, 3), 1);
+    assert_eq!(wt.rank(5, 0), 0);
+    assert_eq!(wt.rank(7, 8), 0); // missing value
+    assert_eq!(wt.kth_smallest(0, 7, 0), Some(1));
+    assert_eq!(wt.kth_smallest(0, 7, 7), Some(9));
+    assert_eq!(wt.kth_smallest(2, 5, 2), Some(5)); // [1,4,5,9]
+    assert_eq!(wt.kth_smallest(2, 5, 4), None);
+}
+
+#[test]
+fn count_less_and_range_freq() {
+    let a = [5, 1, 4, 1, 5, 9, 2, 6];
+    let wt = WaveletTree::new(&a);
+    assert_eq!(wt.count_less(0, 7, 5), 4);
+    assert_eq!(wt.count_less(0, 7, 0), 0);
+    assert_eq!(wt.count_less(0, 7, 100), 8);
+    assert_eq!(wt.range_freq(1, 6, 2, 5), 3); // 4, 5, 2
+    assert_eq!(wt.range_freq(0, 7, 7, 8), 0);
+    assert_eq!(wt.range_freq(0, 7, 6, 2), 0);
+}
+
+#[test]
+fn empty_and_constant_sequences() {
+    let wt = WaveletTree::<i32>::new(&[]);
+    assert!(wt.is_empty());
+    assert_eq!(wt.rank(3, 0), 0);
+
+    let wt = WaveletTree::new(&[7; 5]);
+    assert_eq!(wt.rank(7, 4), 4);
+    assert_eq!(wt.kth_smallest(1, 3, 2), Some(7));
+    assert_eq!(wt.range_freq(0, 4, 7, 7), 5);
+}
+
+#[test]
+#[should_panic(expected = "Range not supported")]
+fn out_of_bounds_query() {
+    WaveletTree::new(&[1, 2, 3]).count_less(0, 3, 2);
+}
+
+/* ---------- property‑based randomized test ---------- */
+
+#[test]
+fn random_queries_against_naive() {
+    let mut rng = StdRng::seed_from_u64(42);
+    for n in [1, 2, 7, 40] {
+        let a: Vec<i32> = (0..n).map(|_| rng.random_range(-10..10)).collect();
+        let wt = WaveletTree::new(&a);
+
+        for i in 0..=n {
+            let v = rng.random_range(-11..11);
+            assert_eq!(wt.rank(v, i), a[..i].iter().filter(|&&x| x == v).count());
+        }
+        for l in 0..n {
+            for r in l..n {
+                let mut sorted = a[l..=r].to_vec();
+                sorted.sort();
+                for (k, &v) in sorted.iter().enumerate() {
+                    assert_eq!(wt.kth_smallest(l, r, k), Some(v));
+                }
+                assert_eq!(wt.kth_smallest(l, r, sorted.len()), None);
+
+                let (lo, hi) = (rng.random_range(-12..12), rng.random_range(-12..12));
+                let expected = sorted.iter().filter(|&&v| lo <= v && v <= hi).count();
+                assert_eq!(wt.range_freq(l, r, lo, hi), expected, "({l},{r},{lo},{hi})");
+                let expected = sorted.iter().filter(|&&v| v < hi).count();
+                assert_eq!(wt.count_less(l, r, hi), expected);
+            }
+        }
+    }
+}
+
+/* ---------- hands_on_2 is_there test suite ---------- */
+
+///
+/// Answers `IsThere(i,j,k)`: is there a point in `[i,j]` covered by exactly `k` segments.
+///
+/// The coverage of each point is computed with a difference array, then every query is a
+/// `range_freq(i, j, k, k)` on the coverage, in `O(log(n))` with `O(nlog(n))` counts instead
+/// of a `HashSet` per segment tree node.
+fn is_there(input: &str) -> Vec<bool> {
+    let mut lines = input.lines();
+    let mut header = lines.next().unwrap().split_whitespace();
+    let n: usize = header.next().unwrap().parse().unwrap();
+    let parse = |line: &str| -> Vec<usize> {
+        line.split_whitespace()
+            .map(|s| s.parse().unwrap())
+            .collect()
+    };
+
+    let mut coverage = vec![0i64; n + 1];
+    for line in lines.by_ref().take(n) {
+        let s = parse(line);
+        coverage[s[0]] += 1;
+        coverage[s[1] + 1] -= 1;
+    }
+    for i in 1..n {
+        coverage[i] += coverage[i - 1];
+    }
+    coverage.truncate(n);
+
+    let wt = WaveletTree::new(&coverage);
+    lines
+        .map(|line| {
+            let q = parse(line);
+            wt.range_freq(q[0], q[1], q[2] as i64, q[2] as i64) > 0
+        })
+        .collect()
+}
+
+#[test]
+fn test_io_is_there() {
+    let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("hands_on_2/test_is_there");
+    for i in 0..=7 {
+        let input = read_to_string(dir.join(format!("input{i}.txt"))).unwrap();
+        let output = read_to_string(dir.join(format!("output{i}.txt"))).unwrap();
+        let expected: Vec<bool> = output.lines().map(|l| l.trim() == "1").collect();
+        assert_eq!(is_there(&input), expected, "input{i}.txt");
+    }
+}